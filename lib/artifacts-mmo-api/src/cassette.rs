@@ -0,0 +1,288 @@
+//! Record-and-replay of request/response pairs, to run tests and bots without network
+//!
+//! Only the method, path and body of the requests are kept, so cassettes never contain the
+//! `Authorization` header and match whatever token is used on replay.
+
+use std::io::{Read, Write};
+
+use base64::{prelude::BASE64_STANDARD, Engine};
+use http::StatusCode;
+use serde::{Deserialize, Serialize};
+use serde_json::{value::RawValue, Value};
+
+use crate::{EncodedRequest, ParseResponse};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Interaction {
+    pub method: String,
+    pub path: String,
+    /// JSON bodies are stored as JSON to keep cassettes readable, `null` when empty
+    pub body: Value,
+    pub status: u16,
+    /// JSON responses are stored verbatim so that replay returns the recorded bytes
+    pub response: Option<Box<RawValue>>,
+    /// Base64 of a response that isn't JSON
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw_response: Option<String>,
+}
+
+impl Interaction {
+    fn matches<T>(&self, request: &EncodedRequest<T>) -> bool {
+        self.method == request.method.as_str()
+            && same_path(&self.path, request.path.as_str())
+            && self.body == to_value(&request.content)
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Cassette {
+    interactions: Vec<Interaction>,
+    #[serde(skip)]
+    replaying: bool,
+    #[serde(skip)]
+    played: Vec<bool>,
+}
+
+impl Cassette {
+    /// Empty cassette recording the interactions
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load a cassette to replay its interactions
+    pub fn load(reader: impl Read) -> Result<Self, crate::Error> {
+        let mut cassette: Self = serde_json::from_reader(reader)?;
+        cassette.replaying = true;
+        cassette.played = vec![false; cassette.interactions.len()];
+        Ok(cassette)
+    }
+
+    pub fn save(&self, writer: impl Write) -> Result<(), crate::Error> {
+        Ok(serde_json::to_writer_pretty(writer, self)?)
+    }
+
+    pub fn is_replaying(&self) -> bool {
+        self.replaying
+    }
+
+    pub fn interactions(&self) -> &[Interaction] {
+        &self.interactions
+    }
+
+    pub fn record<T>(&mut self, request: &EncodedRequest<T>, status: StatusCode, body: &[u8]) {
+        let json = std::str::from_utf8(body)
+            .ok()
+            .and_then(|text| RawValue::from_string(text.to_string()).ok())
+            // Surrounding whitespace is trimmed by `RawValue`
+            .filter(|json| json.get().as_bytes() == body);
+        let raw = (json.is_none() && !body.is_empty()).then(|| BASE64_STANDARD.encode(body));
+        self.interactions.push(Interaction {
+            method: request.method.to_string(),
+            path: request.path.to_string(),
+            body: to_value(&request.content),
+            status: status.as_u16(),
+            response: json,
+            raw_response: raw,
+        });
+        self.played.push(false);
+    }
+
+    /// Response of the first interaction matching `request` that was not replayed yet, so the
+    /// same request can get different responses in the recorded order
+    pub fn replay<T>(&mut self, request: &EncodedRequest<T>) -> Option<(StatusCode, Vec<u8>)> {
+        let index = self
+            .interactions
+            .iter()
+            .zip(&self.played)
+            .position(|(interaction, played)| !played && interaction.matches(request))?;
+        self.played[index] = true;
+
+        let interaction = &self.interactions[index];
+        let body = match (&interaction.response, &interaction.raw_response) {
+            (Some(json), _) => json.get().as_bytes().to_vec(),
+            (None, Some(raw)) => BASE64_STANDARD.decode(raw).ok()?,
+            (None, None) => Vec::new(),
+        };
+        Some((StatusCode::from_u16(interaction.status).ok()?, body))
+    }
+
+    /// Replay `request` and parse the recorded response like a real one
+    pub fn respond<T, R>(&mut self, request: &EncodedRequest<T>) -> Result<R, crate::Error>
+    where
+        EncodedRequest<T>: for<'de> ParseResponse<'de, Response = R>,
+    {
+        let (status, body) = self
+            .replay(request)
+            .ok_or_else(|| crate::Error::NotRecorded {
+                method: request.method.to_string(),
+                path: request.path.to_string(),
+            })?;
+        <EncodedRequest<T> as ParseResponse>::parse_http_response(status, &body)
+    }
+}
+
+fn to_value(bytes: &[u8]) -> Value {
+    if bytes.is_empty() {
+        return Value::Null;
+    }
+    serde_json::from_slice(bytes)
+        .unwrap_or_else(|_| Value::String(String::from_utf8_lossy(bytes).into_owned()))
+}
+
+/// Same path and same query parameters in any order, empty pairs left by a trailing `&` are
+/// ignored
+fn same_path(recorded: &str, requested: &str) -> bool {
+    fn split(path: &str) -> (&str, Vec<&str>) {
+        let (path, query) = path.split_once('?').unwrap_or((path, ""));
+        let mut pairs: Vec<&str> = query.split('&').filter(|pair| !pair.is_empty()).collect();
+        pairs.sort_unstable();
+        (path, pairs)
+    }
+    split(recorded) == split(requested)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{marker::PhantomData, str::FromStr};
+
+    use http::{
+        header::AUTHORIZATION, uri::PathAndQuery, HeaderMap, HeaderValue, Method, StatusCode,
+    };
+
+    use super::Cassette;
+    use crate::{
+        endpoints::{action_move, ActionMoveRequest, GetAllItemsRequest},
+        rate_limits::DATA_RATE_LIMIT,
+        schemas::BearerToken,
+        EncodedRequest, Error,
+    };
+
+    const MOVE_RESPONSE: &[u8] =
+        br#"{"error":{"code":490,"message":"Character already at destination."}}"#;
+
+    fn move_request(token: &str) -> EncodedRequest<ActionMoveRequest> {
+        action_move(
+            ActionMoveRequest::builder()
+                .bearer_token(BearerToken(token.to_string()))
+                .name("Bob")
+                .x(1)
+                .y(-2)
+                .build(),
+        )
+        .unwrap()
+    }
+
+    fn items_request(path: &str) -> EncodedRequest<GetAllItemsRequest> {
+        EncodedRequest {
+            method: Method::GET,
+            path: PathAndQuery::from_str(path).unwrap(),
+            headers: HeaderMap::new(),
+            content: Vec::new(),
+            rate_limit: DATA_RATE_LIMIT,
+            marker: PhantomData,
+        }
+    }
+
+    #[test]
+    fn replay_should_ignore_authorization() {
+        let mut cassette = Cassette::new();
+        cassette.record(
+            &move_request("secret"),
+            StatusCode::from_u16(490).unwrap(),
+            MOVE_RESPONSE,
+        );
+
+        let mut saved = Vec::new();
+        cassette.save(&mut saved).unwrap();
+        assert!(!String::from_utf8_lossy(&saved).contains("secret"));
+
+        let mut cassette = Cassette::load(saved.as_slice()).unwrap();
+        let mut request = move_request("other");
+        request
+            .headers
+            .insert(AUTHORIZATION, HeaderValue::from_static("Bearer other"));
+        assert!(matches!(
+            cassette.respond(&request),
+            Err(Error::AlreadyAtDestination(_))
+        ));
+        // Every interaction is replayed once
+        assert!(matches!(
+            cassette.respond(&request),
+            Err(Error::NotRecorded { .. })
+        ));
+    }
+
+    #[test]
+    fn replay_should_tolerate_query_order() {
+        let mut cassette = Cassette::new();
+        cassette.record(
+            &items_request("/items/?page=1&size=50&type=ring&"),
+            StatusCode::OK,
+            br#"{"data":[],"total":0,"page":1,"size":50,"pages":0}"#,
+        );
+
+        assert!(cassette
+            .replay(&items_request("/items/?type=ring&size=50&page=1"))
+            .is_some());
+        cassette.played[0] = false;
+        assert!(cassette
+            .replay(&items_request("/items/?page=2&size=50&type=ring"))
+            .is_none());
+        assert!(cassette
+            .replay(&items_request("/monsters/?page=1&size=50&type=ring"))
+            .is_none());
+    }
+
+    #[test]
+    fn replay_should_return_the_recorded_bytes() {
+        let responses: [&[u8]; 6] = [
+            b"",
+            br#""abc""#,
+            b"abc",
+            br#"{ "b": 1.50, "a": [] }"#,
+            b" {}\n",
+            &[0xff, 0xfe],
+        ];
+        let mut cassette = Cassette::new();
+        for response in responses {
+            cassette.record(&move_request("token"), StatusCode::OK, response);
+        }
+        let mut saved = Vec::new();
+        cassette.save(&mut saved).unwrap();
+
+        let mut cassette = Cassette::load(saved.as_slice()).unwrap();
+        for response in responses {
+            assert_eq!(
+                cassette.replay(&move_request("token")),
+                Some((StatusCode::OK, response.to_vec()))
+            );
+        }
+    }
+
+    #[test]
+    fn replay_should_follow_recorded_order() {
+        let mut cassette = Cassette::new();
+        cassette.record(
+            &move_request("token"),
+            StatusCode::from_u16(499).unwrap(),
+            b"first",
+        );
+        cassette.record(
+            &move_request("token"),
+            StatusCode::from_u16(490).unwrap(),
+            b"second",
+        );
+        let mut other_body = move_request("token");
+        other_body.content = br#"{"x":3,"y":3}"#.to_vec();
+
+        assert!(cassette.replay(&other_body).is_none());
+        assert_eq!(
+            cassette.replay(&move_request("token")),
+            Some((StatusCode::from_u16(499).unwrap(), b"first".to_vec()))
+        );
+        assert_eq!(
+            cassette.replay(&move_request("token")),
+            Some((StatusCode::from_u16(490).unwrap(), b"second".to_vec()))
+        );
+    }
+}
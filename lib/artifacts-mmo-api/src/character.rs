@@ -0,0 +1,312 @@
+//! Typed views over the flat fields of `CharacterSchema`
+
+use std::collections::HashMap;
+
+use crate::schemas::{CharacterSchema, CraftSkillSchema, SkillSchema, SlotTypeSchema};
+
+/// Every slot of `SlotTypeSchema`, in the order of the `*_slot` fields
+pub const SLOTS: [SlotTypeSchema; 14] = [
+    SlotTypeSchema::Weapon,
+    SlotTypeSchema::Shield,
+    SlotTypeSchema::Helmet,
+    SlotTypeSchema::BodyArmor,
+    SlotTypeSchema::LegArmor,
+    SlotTypeSchema::Boots,
+    SlotTypeSchema::Ring1,
+    SlotTypeSchema::Ring2,
+    SlotTypeSchema::Amulet,
+    SlotTypeSchema::Artifact1,
+    SlotTypeSchema::Artifact2,
+    SlotTypeSchema::Artifact3,
+    SlotTypeSchema::Consumable1,
+    SlotTypeSchema::Consumable2,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SkillProgress {
+    pub level: u32,
+    pub xp: u32,
+    pub max_xp: u32,
+}
+
+/// Skill enums of the schemas, read from the `<skill>_level`, `<skill>_xp` and
+/// `<skill>_max_xp` fields of `CharacterSchema`
+pub trait CharacterSkill {
+    /// `None` for a skill unknown to this crate
+    fn progress(&self, character: &CharacterSchema) -> Option<SkillProgress>;
+}
+
+impl CharacterSkill for SkillSchema {
+    fn progress(&self, character: &CharacterSchema) -> Option<SkillProgress> {
+        character.skill_named(&self.to_string())
+    }
+}
+
+impl CharacterSkill for CraftSkillSchema {
+    fn progress(&self, character: &CharacterSchema) -> Option<SkillProgress> {
+        character.skill_named(&self.to_string())
+    }
+}
+
+/// Item code per slot, `None` when the slot is empty
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Equipment(HashMap<SlotTypeSchema, Option<String>>);
+
+impl Equipment {
+    pub fn get(&self, slot: &SlotTypeSchema) -> Option<&str> {
+        self.0.get(slot).and_then(Option::as_deref)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&SlotTypeSchema, Option<&str>)> {
+        SLOTS
+            .iter()
+            .filter_map(|slot| self.0.get_key_value(slot))
+            .map(|(slot, code)| (slot, code.as_deref()))
+    }
+
+    pub fn is_equipped(&self, code: &str) -> bool {
+        self.0
+            .values()
+            .any(|equipped| equipped.as_deref() == Some(code))
+    }
+}
+
+impl CharacterSchema {
+    /// Item code equipped in `slot`, `None` when the slot is empty
+    pub fn slot(&self, slot: &SlotTypeSchema) -> Option<&str> {
+        let code = match slot {
+            SlotTypeSchema::Weapon => &self.weapon_slot,
+            SlotTypeSchema::Shield => &self.shield_slot,
+            SlotTypeSchema::Helmet => &self.helmet_slot,
+            SlotTypeSchema::BodyArmor => &self.body_armor_slot,
+            SlotTypeSchema::LegArmor => &self.leg_armor_slot,
+            SlotTypeSchema::Boots => &self.boots_slot,
+            SlotTypeSchema::Ring1 => &self.ring1_slot,
+            SlotTypeSchema::Ring2 => &self.ring2_slot,
+            SlotTypeSchema::Amulet => &self.amulet_slot,
+            SlotTypeSchema::Artifact1 => &self.artifact1_slot,
+            SlotTypeSchema::Artifact2 => &self.artifact2_slot,
+            SlotTypeSchema::Artifact3 => &self.artifact3_slot,
+            SlotTypeSchema::Consumable1 => &self.consumable1_slot,
+            SlotTypeSchema::Consumable2 => &self.consumable2_slot,
+            SlotTypeSchema::Unknown(_) => return None,
+        };
+        (!code.is_empty()).then_some(code.as_str())
+    }
+
+    /// Raw `*_slot` field, an empty string means nothing is equipped
+    pub fn slot_mut(&mut self, slot: &SlotTypeSchema) -> Option<&mut String> {
+        Some(match slot {
+            SlotTypeSchema::Weapon => &mut self.weapon_slot,
+            SlotTypeSchema::Shield => &mut self.shield_slot,
+            SlotTypeSchema::Helmet => &mut self.helmet_slot,
+            SlotTypeSchema::BodyArmor => &mut self.body_armor_slot,
+            SlotTypeSchema::LegArmor => &mut self.leg_armor_slot,
+            SlotTypeSchema::Boots => &mut self.boots_slot,
+            SlotTypeSchema::Ring1 => &mut self.ring1_slot,
+            SlotTypeSchema::Ring2 => &mut self.ring2_slot,
+            SlotTypeSchema::Amulet => &mut self.amulet_slot,
+            SlotTypeSchema::Artifact1 => &mut self.artifact1_slot,
+            SlotTypeSchema::Artifact2 => &mut self.artifact2_slot,
+            SlotTypeSchema::Artifact3 => &mut self.artifact3_slot,
+            SlotTypeSchema::Consumable1 => &mut self.consumable1_slot,
+            SlotTypeSchema::Consumable2 => &mut self.consumable2_slot,
+            SlotTypeSchema::Unknown(_) => return None,
+        })
+    }
+
+    /// Stat changed by an item effect named `effect`, effects that are not stats return `None`
+    pub fn stat_mut(&mut self, effect: &str) -> Option<&mut u32> {
+        Some(match effect {
+            "hp" => &mut self.hp,
+            "haste" => &mut self.haste,
+            "attack_fire" => &mut self.attack_fire,
+            "attack_earth" => &mut self.attack_earth,
+            "attack_water" => &mut self.attack_water,
+            "attack_air" => &mut self.attack_air,
+            "dmg_fire" => &mut self.dmg_fire,
+            "dmg_earth" => &mut self.dmg_earth,
+            "dmg_water" => &mut self.dmg_water,
+            "dmg_air" => &mut self.dmg_air,
+            "res_fire" => &mut self.res_fire,
+            "res_earth" => &mut self.res_earth,
+            "res_water" => &mut self.res_water,
+            "res_air" => &mut self.res_air,
+            _ => return None,
+        })
+    }
+
+    pub fn equipment(&self) -> Equipment {
+        Equipment(
+            SLOTS
+                .iter()
+                .map(|slot| (slot.clone(), self.slot(slot).map(str::to_string)))
+                .collect(),
+        )
+    }
+
+    /// Quantity of `code` in the inventory, equipped items are not counted
+    pub fn inventory_count(&self, code: &str) -> u32 {
+        self.inventory
+            .iter()
+            .filter(|slot| slot.code == code)
+            .fold(0, |count, slot| count.saturating_add(slot.quantity))
+    }
+
+    /// Sum of the quantities of every item in the inventory
+    pub fn inventory_total(&self) -> u32 {
+        self.inventory
+            .iter()
+            .fold(0, |total, slot| total.saturating_add(slot.quantity))
+    }
+
+    /// Items that can still be added before reaching `inventory_max_items`
+    pub fn inventory_free_space(&self) -> u32 {
+        self.inventory_max_items
+            .saturating_sub(self.inventory_total())
+    }
+
+    /// Inventory slots holding no item, the API sends them with an empty code
+    pub fn inventory_free_slots(&self) -> usize {
+        self.inventory
+            .iter()
+            .filter(|slot| slot.code.is_empty())
+            .count()
+    }
+
+    /// The character level, gained by fighting
+    pub fn combat(&self) -> SkillProgress {
+        SkillProgress {
+            level: self.level,
+            xp: self.xp,
+            max_xp: self.max_xp,
+        }
+    }
+
+    /// Progress of a gathering or crafting skill, `None` for a skill unknown to this crate
+    pub fn skill(&self, skill: &impl CharacterSkill) -> Option<SkillProgress> {
+        skill.progress(self)
+    }
+
+    fn skill_named(&self, name: &str) -> Option<SkillProgress> {
+        let (level, xp, max_xp) = match name {
+            "mining" => (self.mining_level, self.mining_xp, self.mining_max_xp),
+            "woodcutting" => (
+                self.woodcutting_level,
+                self.woodcutting_xp,
+                self.woodcutting_max_xp,
+            ),
+            "fishing" => (self.fishing_level, self.fishing_xp, self.fishing_max_xp),
+            "weaponcrafting" => (
+                self.weaponcrafting_level,
+                self.weaponcrafting_xp,
+                self.weaponcrafting_max_xp,
+            ),
+            "gearcrafting" => (
+                self.gearcrafting_level,
+                self.gearcrafting_xp,
+                self.gearcrafting_max_xp,
+            ),
+            "jewelrycrafting" => (
+                self.jewelrycrafting_level,
+                self.jewelrycrafting_xp,
+                self.jewelrycrafting_max_xp,
+            ),
+            "cooking" => (self.cooking_level, self.cooking_xp, self.cooking_max_xp),
+            _ => return None,
+        };
+        Some(SkillProgress { level, xp, max_xp })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::SkillProgress;
+    use crate::{
+        schemas::{CraftSkillSchema, InventorySlotSchema, SkillSchema, SlotTypeSchema},
+        test_support::character,
+    };
+
+    fn slot(slot: u32, code: &str, quantity: u32) -> InventorySlotSchema {
+        InventorySlotSchema {
+            slot,
+            code: code.to_string(),
+            quantity,
+        }
+    }
+
+    #[test]
+    fn equipment_should_map_empty_slots_to_none() {
+        let character = character(
+            100,
+            json!({ "weapon_slot": "wooden_stick", "ring2_slot": "copper_ring" }),
+        );
+        let equipment = character.equipment();
+
+        assert_eq!(equipment.get(&SlotTypeSchema::Weapon), Some("wooden_stick"));
+        assert_eq!(equipment.get(&SlotTypeSchema::Ring1), None);
+        assert_eq!(equipment.get(&SlotTypeSchema::Ring2), Some("copper_ring"));
+        assert!(equipment.is_equipped("copper_ring"));
+        assert_eq!(equipment.iter().count(), 14);
+        assert_eq!(
+            equipment.iter().filter(|(_, code)| code.is_some()).count(),
+            2
+        );
+    }
+
+    #[test]
+    fn inventory_queries_should_count_items() {
+        let mut character = character(100, json!({ "inventory_max_items": 20 }));
+        character.inventory = vec![
+            slot(1, "copper_ore", 5),
+            slot(2, "ash_wood", 3),
+            slot(3, "copper_ore", 4),
+            slot(4, "", 0),
+        ];
+
+        assert_eq!(character.inventory_count("copper_ore"), 9);
+        assert_eq!(character.inventory_count("gudgeon"), 0);
+        assert_eq!(character.inventory_total(), 12);
+        assert_eq!(character.inventory_free_space(), 8);
+        assert_eq!(character.inventory_free_slots(), 1);
+
+        character.inventory = vec![slot(1, "copper_ore", u32::MAX), slot(2, "copper_ore", 1)];
+        assert_eq!(character.inventory_count("copper_ore"), u32::MAX);
+        assert_eq!(character.inventory_total(), u32::MAX);
+    }
+
+    #[test]
+    fn skill_should_read_matching_fields() {
+        let character = character(
+            100,
+            json!({ "level": 7, "xp": 10, "max_xp": 300, "cooking_level": 3, "cooking_xp": 42 }),
+        );
+
+        assert_eq!(
+            character.combat(),
+            SkillProgress {
+                level: 7,
+                xp: 10,
+                max_xp: 300
+            }
+        );
+        assert_eq!(
+            character.skill(&CraftSkillSchema::Cooking),
+            Some(SkillProgress {
+                level: 3,
+                xp: 42,
+                max_xp: 150
+            })
+        );
+        assert_eq!(
+            character.skill(&SkillSchema::Mining),
+            character.skill(&CraftSkillSchema::Mining)
+        );
+        assert_eq!(
+            character.skill(&SkillSchema::Unknown("alchemy".to_string())),
+            None
+        );
+    }
+}
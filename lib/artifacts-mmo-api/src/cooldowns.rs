@@ -0,0 +1,269 @@
+//! Track when characters are off cooldown and release queued actions accordingly
+//! SOURCE: <https://docs.artifactsmmo.com/concepts/actions>
+
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+
+use chrono::{DateTime, Utc};
+
+use crate::schemas::{
+    BankItemSchema, CharacterFightDataSchema, CharacterMovementDataSchema, CharacterSchema,
+    DeleteItemSchema, EquipRequestSchema, GETransactionListSchema, GoldTransactionSchema,
+    RecyclingDataSchema, ResponseSchema, SkillDataSchema, TaskDataSchema, TaskRewardDataSchema,
+};
+
+/// Data returned by an action, every action puts the character in cooldown
+pub trait ActionData {
+    fn character_name(&self) -> &str;
+    fn cooldown_expiration(&self) -> DateTime<Utc>;
+}
+
+macro_rules! impl_action_data {
+    ($($schema:ty),+) => {
+        $(
+        impl ActionData for $schema {
+            fn character_name(&self) -> &str {
+                &self.character.name
+            }
+
+            fn cooldown_expiration(&self) -> DateTime<Utc> {
+                self.cooldown.expiration
+            }
+        }
+        )+
+    };
+}
+
+impl_action_data! {
+    CharacterMovementDataSchema,
+    CharacterFightDataSchema,
+    EquipRequestSchema,
+    SkillDataSchema,
+    BankItemSchema,
+    GoldTransactionSchema,
+    GETransactionListSchema,
+    TaskDataSchema,
+    TaskRewardDataSchema,
+    RecyclingDataSchema,
+    DeleteItemSchema
+}
+
+impl<T: ActionData> ActionData for ResponseSchema<T> {
+    fn character_name(&self) -> &str {
+        self.data.character_name()
+    }
+
+    fn cooldown_expiration(&self) -> DateTime<Utc> {
+        self.data.cooldown_expiration()
+    }
+}
+
+/// Readiness of every character seen in action responses, characters never seen are ready
+#[derive(Debug, Clone, Default)]
+pub struct CooldownTracker {
+    expirations: HashMap<String, DateTime<Utc>>,
+}
+
+impl CooldownTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn ingest(&mut self, response: &impl ActionData) {
+        self.record(response.character_name(), response.cooldown_expiration());
+    }
+
+    pub fn ingest_character(&mut self, character: &CharacterSchema) {
+        self.record(&character.name, character.cooldown_expiration);
+    }
+
+    pub fn record(&mut self, name: &str, expiration: DateTime<Utc>) {
+        self.expirations
+            .entry(name.to_string())
+            .and_modify(|current| *current = expiration.max(*current))
+            .or_insert(expiration);
+    }
+
+    pub fn next_ready(&self, name: &str) -> Option<DateTime<Utc>> {
+        self.expirations.get(name).copied()
+    }
+
+    pub fn is_ready(&self, name: &str, now: DateTime<Utc>) -> bool {
+        self.next_ready(name)
+            .is_none_or(|expiration| expiration <= now)
+    }
+}
+
+/// Queue of actions per character, an action is released only when its character is off
+/// cooldown and the response of its previous action has been ingested
+#[derive(Debug, Clone)]
+pub struct Scheduler<R> {
+    tracker: CooldownTracker,
+    queues: BTreeMap<String, VecDeque<R>>,
+    in_flight: HashSet<String>,
+}
+
+impl<R> Default for Scheduler<R> {
+    fn default() -> Self {
+        Self {
+            tracker: CooldownTracker::default(),
+            queues: BTreeMap::new(),
+            in_flight: HashSet::new(),
+        }
+    }
+}
+
+impl<R> Scheduler<R> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn tracker(&self) -> &CooldownTracker {
+        &self.tracker
+    }
+
+    pub fn push(&mut self, name: impl Into<String>, request: R) {
+        self.queues
+            .entry(name.into())
+            .or_default()
+            .push_back(request);
+    }
+
+    pub fn pending(&self, name: &str) -> usize {
+        self.queues.get(name).map_or(0, VecDeque::len)
+    }
+
+    /// Release the next action of the first character ready at `now`
+    pub fn pop_ready(&mut self, now: DateTime<Utc>) -> Option<(String, R)> {
+        let name = self
+            .queues
+            .iter()
+            .find(|(name, queue)| {
+                !queue.is_empty()
+                    && !self.in_flight.contains(*name)
+                    && self.tracker.is_ready(name, now)
+            })
+            .map(|(name, _)| name.clone())?;
+        let request = self.queues.get_mut(&name)?.pop_front()?;
+        self.in_flight.insert(name.clone());
+
+        Some((name, request))
+    }
+
+    /// Earliest time an action could be released, `None` if nothing can be released without
+    /// ingesting a response first
+    pub fn next_wake(&self) -> Option<DateTime<Utc>> {
+        self.queues
+            .iter()
+            .filter(|(name, queue)| !queue.is_empty() && !self.in_flight.contains(*name))
+            .map(|(name, _)| {
+                self.tracker
+                    .next_ready(name)
+                    .unwrap_or(DateTime::<Utc>::MIN_UTC)
+            })
+            .min()
+    }
+
+    pub fn ingest(&mut self, response: &impl ActionData) {
+        self.in_flight.remove(response.character_name());
+        self.tracker.ingest(response);
+    }
+
+    /// Mark the released action of the character as done without a response, e.g. on error
+    pub fn release(&mut self, name: &str) {
+        self.in_flight.remove(name);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Duration, TimeZone, Utc};
+
+    use super::{ActionData, CooldownTracker, Scheduler};
+
+    struct FakeAction {
+        name: &'static str,
+        expiration: DateTime<Utc>,
+    }
+
+    impl ActionData for FakeAction {
+        fn character_name(&self) -> &str {
+            self.name
+        }
+
+        fn cooldown_expiration(&self) -> DateTime<Utc> {
+            self.expiration
+        }
+    }
+
+    fn start() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 8, 4, 12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn tracker_should_consider_unknown_characters_ready() {
+        let tracker = CooldownTracker::new();
+        assert!(tracker.is_ready("Bob", start()));
+        assert_eq!(tracker.next_ready("Bob"), None);
+    }
+
+    #[test]
+    fn tracker_should_wait_for_expiration() {
+        let mut tracker = CooldownTracker::new();
+        tracker.ingest(&FakeAction {
+            name: "Bob",
+            expiration: start() + Duration::seconds(5),
+        });
+
+        assert!(!tracker.is_ready("Bob", start()));
+        assert!(tracker.is_ready("Bob", start() + Duration::seconds(5)));
+        assert!(tracker.is_ready("Alice", start()));
+    }
+
+    #[test]
+    fn tracker_should_keep_latest_expiration() {
+        let mut tracker = CooldownTracker::new();
+        tracker.record("Bob", start() + Duration::seconds(10));
+        tracker.record("Bob", start() + Duration::seconds(5));
+
+        assert_eq!(
+            tracker.next_ready("Bob"),
+            Some(start() + Duration::seconds(10))
+        );
+    }
+
+    #[test]
+    fn scheduler_should_release_one_action_per_character_until_ingested() {
+        let mut scheduler = Scheduler::new();
+        scheduler.push("Bob", 1);
+        scheduler.push("Bob", 2);
+        scheduler.push("Alice", 3);
+
+        assert_eq!(scheduler.pop_ready(start()), Some(("Alice".to_string(), 3)));
+        assert_eq!(scheduler.pop_ready(start()), Some(("Bob".to_string(), 1)));
+        assert_eq!(scheduler.pop_ready(start()), None);
+
+        scheduler.ingest(&FakeAction {
+            name: "Bob",
+            expiration: start() + Duration::seconds(3),
+        });
+        assert_eq!(scheduler.next_wake(), Some(start() + Duration::seconds(3)));
+        assert_eq!(scheduler.pop_ready(start()), None);
+        assert_eq!(
+            scheduler.pop_ready(start() + Duration::seconds(3)),
+            Some(("Bob".to_string(), 2))
+        );
+        assert_eq!(scheduler.pending("Bob"), 0);
+    }
+
+    #[test]
+    fn scheduler_should_release_after_failure() {
+        let mut scheduler = Scheduler::new();
+        scheduler.push("Bob", 1);
+        scheduler.push("Bob", 2);
+
+        assert_eq!(scheduler.pop_ready(start()), Some(("Bob".to_string(), 1)));
+        assert_eq!(scheduler.next_wake(), None);
+        scheduler.release("Bob");
+        assert_eq!(scheduler.pop_ready(start()), Some(("Bob".to_string(), 2)));
+    }
+}
@@ -0,0 +1,361 @@
+//! Expansion of crafting recipes into the raw resources needed to craft an item
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::{
+    game_data::GameData,
+    schemas::{CraftSkillSchema, InventorySlotSchema, ItemSchema, SimpleItemSchema},
+    Error,
+};
+
+/// One crafting action, `times` crafts yield `times * recipe quantity` items
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CraftStep {
+    pub code: String,
+    pub skill: CraftSkillSchema,
+    pub level: u32,
+    pub times: u32,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CraftPlan {
+    /// Resources that have to be gathered, bought or dropped
+    pub missing: BTreeMap<String, u32>,
+    /// Items taken from the inventory and the bank
+    pub used: BTreeMap<String, u32>,
+    /// Crafts in an order where every ingredient is crafted before it is needed
+    pub steps: Vec<CraftStep>,
+    /// Highest level required for each skill
+    pub skill_levels: HashMap<CraftSkillSchema, u32>,
+}
+
+/// Items available while planning
+#[derive(Debug, Default)]
+struct Stock {
+    /// Inventory and bank, reported in `CraftPlan::used` when taken
+    owned: HashMap<String, u32>,
+    /// Left over by crafts yielding more items than needed
+    surplus: HashMap<String, u32>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Recipes<'a> {
+    items: HashMap<&'a str, &'a ItemSchema>,
+}
+
+impl<'a> Recipes<'a> {
+    pub fn new(items: impl IntoIterator<Item = &'a ItemSchema>) -> Self {
+        Self {
+            items: items
+                .into_iter()
+                .map(|item| (item.code.as_str(), item))
+                .collect(),
+        }
+    }
+
+    /// Plan `quantity` crafts of `code`, intermediate items and resources are taken from the
+    /// inventory and the bank first, the target itself is always crafted
+    pub fn plan(
+        &self,
+        code: &str,
+        quantity: u32,
+        inventory: &[InventorySlotSchema],
+        bank: &[SimpleItemSchema],
+    ) -> Result<CraftPlan, Error> {
+        let mut stock = Stock::default();
+        for (code, quantity) in inventory
+            .iter()
+            .map(|slot| (&slot.code, slot.quantity))
+            .chain(bank.iter().map(|item| (&item.code, item.quantity)))
+        {
+            if !code.is_empty() {
+                let available = stock.owned.entry(code.clone()).or_default();
+                *available = available.saturating_add(quantity);
+            }
+        }
+
+        let mut plan = CraftPlan::default();
+        let mut path = Vec::new();
+        self.craft(code, quantity, &mut stock, &mut path, &mut plan)?;
+        Ok(plan)
+    }
+
+    fn expand(
+        &self,
+        code: &str,
+        quantity: u32,
+        stock: &mut Stock,
+        path: &mut Vec<String>,
+        plan: &mut CraftPlan,
+    ) -> Result<(), Error> {
+        // Leftovers of previous crafts first, they would be wasted otherwise
+        let crafted = take(&mut stock.surplus, code, quantity);
+        let owned = take(&mut stock.owned, code, quantity - crafted);
+        if owned > 0 {
+            add(&mut plan.used, code, owned)?;
+        }
+
+        let needed = quantity - crafted - owned;
+        if needed == 0 {
+            return Ok(());
+        }
+        if self
+            .items
+            .get(code)
+            .is_some_and(|item| item.craft.is_some())
+        {
+            self.craft(code, needed, stock, path, plan)
+        } else {
+            add(&mut plan.missing, code, needed)
+        }
+    }
+
+    fn craft(
+        &self,
+        code: &str,
+        quantity: u32,
+        stock: &mut Stock,
+        path: &mut Vec<String>,
+        plan: &mut CraftPlan,
+    ) -> Result<(), Error> {
+        let Some(craft) = self.items.get(code).and_then(|item| item.craft.as_ref()) else {
+            return add(&mut plan.missing, code, quantity);
+        };
+        if let Some(start) = path.iter().position(|visited| visited == code) {
+            let mut cycle = path[start..].to_vec();
+            cycle.push(code.to_string());
+            return Err(Error::CraftCycle(cycle));
+        }
+
+        let overflow = || Error::CraftOverflow(code.to_string());
+        let times = quantity.div_ceil(craft.quantity.max(1));
+        let surplus = times
+            .checked_mul(craft.quantity.max(1))
+            .ok_or_else(overflow)?
+            - quantity;
+        path.push(code.to_string());
+        for ingredient in &craft.items {
+            let quantity = ingredient
+                .quantity
+                .checked_mul(times)
+                .ok_or_else(overflow)?;
+            self.expand(&ingredient.code, quantity, stock, path, plan)?;
+        }
+        path.pop();
+
+        if surplus > 0 {
+            let available = stock.surplus.entry(code.to_string()).or_default();
+            *available = available.saturating_add(surplus);
+        }
+        let level = plan.skill_levels.entry(craft.skill.clone()).or_default();
+        *level = (*level).max(craft.level);
+        plan.steps.push(CraftStep {
+            code: code.to_string(),
+            skill: craft.skill.clone(),
+            level: craft.level,
+            times,
+        });
+        Ok(())
+    }
+}
+
+/// Take up to `quantity` items of `code`, returns how many were taken
+fn take(stock: &mut HashMap<String, u32>, code: &str, quantity: u32) -> u32 {
+    stock.get_mut(code).map_or(0, |available| {
+        let taken = (*available).min(quantity);
+        *available -= taken;
+        taken
+    })
+}
+
+fn add(totals: &mut BTreeMap<String, u32>, code: &str, quantity: u32) -> Result<(), Error> {
+    let total = totals.entry(code.to_string()).or_default();
+    *total = total
+        .checked_add(quantity)
+        .ok_or_else(|| Error::CraftOverflow(code.to_string()))?;
+    Ok(())
+}
+
+impl<'a> From<&'a GameData> for Recipes<'a> {
+    fn from(game_data: &'a GameData) -> Self {
+        Self::new(game_data.items())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::{CraftStep, Recipes};
+    use crate::{
+        schemas::{
+            CraftSchema, CraftSkillSchema, InventorySlotSchema, ItemSchema, ItemTypeSchema,
+            SimpleItemSchema,
+        },
+        Error,
+    };
+
+    fn item(code: &str, craft: Option<CraftSchema>) -> ItemSchema {
+        ItemSchema {
+            name: code.to_string(),
+            code: code.to_string(),
+            level: 1,
+            r#type: ItemTypeSchema::Resource,
+            subtype: String::new(),
+            description: String::new(),
+            effects: Vec::new(),
+            craft,
+        }
+    }
+
+    fn recipe(
+        skill: CraftSkillSchema,
+        level: u32,
+        items: &[(&str, u32)],
+        quantity: u32,
+    ) -> Option<CraftSchema> {
+        Some(CraftSchema {
+            skill,
+            level,
+            items: items
+                .iter()
+                .map(|(code, quantity)| SimpleItemSchema {
+                    code: code.to_string(),
+                    quantity: *quantity,
+                })
+                .collect(),
+            quantity,
+        })
+    }
+
+    fn items() -> Vec<ItemSchema> {
+        vec![
+            item("copper_ore", None),
+            item("ash_wood", None),
+            item(
+                "copper",
+                recipe(CraftSkillSchema::Mining, 1, &[("copper_ore", 6)], 1),
+            ),
+            item(
+                "ash_plank",
+                recipe(CraftSkillSchema::Woodcutting, 1, &[("ash_wood", 6)], 2),
+            ),
+            item(
+                "copper_dagger",
+                recipe(
+                    CraftSkillSchema::WeaponCrafting,
+                    5,
+                    &[("copper", 3), ("ash_plank", 1)],
+                    1,
+                ),
+            ),
+        ]
+    }
+
+    #[test]
+    fn plan_should_expand_into_raw_resources() {
+        let items = items();
+        let plan = Recipes::new(&items)
+            .plan("copper_dagger", 2, &[], &[])
+            .unwrap();
+
+        assert_eq!(plan.missing.get("copper_ore"), Some(&36));
+        // Two planks come out of a single craft
+        assert_eq!(plan.missing.get("ash_wood"), Some(&6));
+        assert!(plan.used.is_empty());
+        assert_eq!(
+            plan.steps.last(),
+            Some(&CraftStep {
+                code: "copper_dagger".to_string(),
+                skill: CraftSkillSchema::WeaponCrafting,
+                level: 5,
+                times: 2,
+            })
+        );
+        assert_eq!(
+            plan.skill_levels.get(&CraftSkillSchema::WeaponCrafting),
+            Some(&5)
+        );
+        assert_eq!(plan.skill_levels.get(&CraftSkillSchema::Mining), Some(&1));
+    }
+
+    #[test]
+    fn plan_should_subtract_inventory_and_bank() {
+        let items = items();
+        let inventory = [InventorySlotSchema {
+            slot: 1,
+            code: "copper".to_string(),
+            quantity: 2,
+        }];
+        let bank = [SimpleItemSchema {
+            code: "copper_ore".to_string(),
+            quantity: 10,
+        }];
+        let plan = Recipes::new(&items)
+            .plan("copper_dagger", 1, &inventory, &bank)
+            .unwrap();
+
+        assert_eq!(plan.used.get("copper"), Some(&2));
+        assert_eq!(plan.used.get("copper_ore"), Some(&6));
+        assert_eq!(plan.missing.get("copper_ore"), None);
+        assert_eq!(plan.missing.get("ash_wood"), Some(&6));
+    }
+
+    #[test]
+    fn plan_should_not_report_crafted_surplus_as_used() {
+        let mut items = items();
+        items.extend([
+            item(
+                "wooden_handle",
+                recipe(CraftSkillSchema::Woodcutting, 1, &[("ash_plank", 1)], 1),
+            ),
+            item(
+                "wooden_shield",
+                recipe(
+                    CraftSkillSchema::GearCrafting,
+                    1,
+                    &[("ash_plank", 1), ("wooden_handle", 1)],
+                    1,
+                ),
+            ),
+        ]);
+        let plan = Recipes::new(&items)
+            .plan("wooden_shield", 1, &[], &[])
+            .unwrap();
+
+        // Both planks come out of the same craft
+        assert!(plan.used.is_empty());
+        assert_eq!(plan.missing.get("ash_wood"), Some(&6));
+        let planks = plan.steps.iter().find(|step| step.code == "ash_plank");
+        assert_eq!(planks.map(|step| step.times), Some(1));
+    }
+
+    #[test]
+    fn plan_should_detect_cycles() {
+        let items = vec![
+            item("a", recipe(CraftSkillSchema::Cooking, 1, &[("b", 1)], 1)),
+            item("b", recipe(CraftSkillSchema::Cooking, 1, &[("a", 1)], 1)),
+        ];
+        let result = Recipes::new(&items).plan("a", 1, &[], &[]);
+
+        assert!(matches!(result, Err(Error::CraftCycle(cycle)) if cycle == ["a", "b", "a"]));
+    }
+
+    proptest! {
+        #[test]
+        fn plan_should_not_panic_with_large_quantities(
+            quantity in u32::MAX / 8..=u32::MAX,
+            stock in any::<u32>(),
+        ) {
+            let items = items();
+            let bank = [SimpleItemSchema {
+                code: "copper_ore".to_string(),
+                quantity: stock,
+            }];
+            match Recipes::new(&items).plan("copper_dagger", quantity, &[], &bank) {
+                Ok(plan) => prop_assert!(plan.steps.iter().all(|step| step.times > 0)),
+                Err(error) => prop_assert!(matches!(error, Error::CraftOverflow(_))),
+            }
+        }
+    }
+}
@@ -14,7 +14,7 @@ use crate::{
     helpers::{ACCEPT_JSON, CONTENT_TYPE_JSON},
     rate_limits::ACCOUNT_CREATION_RATE_LIMIT,
     schemas::MessageSchema,
-    EncodedRequest, ParseResponse,
+    EncodedRequest, ParseResponse, StatusErrors,
 };
 
 #[nutype(validate(
@@ -70,6 +70,10 @@ pub fn create_account(
 
 impl<'de> ParseResponse<'de> for EncodedRequest<CreateAccountRequest> {
     type Response = MessageSchema;
+    const STATUS_ERRORS: StatusErrors = &[
+        (456, crate::Error::UsernameAlreadyUsed),
+        (457, crate::Error::EmailAlreadyUsed),
+    ];
 }
 
 #[cfg(test)]
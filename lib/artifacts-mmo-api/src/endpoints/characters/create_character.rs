@@ -12,7 +12,7 @@ use crate::{
         BearerToken, CharacterSchema, CraftSkillSchema, MonsterSchema, PaginatedResponseSchema,
         ResourceSchema, ResponseSchema, SkillSchema,
     },
-    EncodedRequest, ParseResponse,
+    EncodedRequest, ParseResponse, StatusErrors,
 };
 
 #[nutype(validate(regex = "^[a-zA-Z0-9_-]+$"))]
@@ -58,6 +58,14 @@ pub fn create_character(
     })
 }
 
+impl<'de> ParseResponse<'de> for EncodedRequest<CreateCharacterRequest> {
+    type Response = ResponseSchema<CharacterSchema>;
+    const STATUS_ERRORS: StatusErrors = &[
+        (494, crate::Error::NameAlreadyUsed),
+        (495, crate::Error::MaxCharactersReached),
+    ];
+}
+
 #[cfg(test)]
 mod tests {
     use proptest::prelude::*;
@@ -6,7 +6,8 @@ use serde_json::json;
 use typed_builder::TypedBuilder;
 
 use crate::{
-    helpers::{ACCEPT_JSON, CONTENT_TYPE_JSON},
+    helpers::{Query, ACCEPT_JSON, CONTENT_TYPE_JSON},
+    pagination::PaginatedRequest,
     rate_limits::DATA_RATE_LIMIT,
     schemas::{
         BearerToken, CharacterSchema, CraftSkillSchema, MonsterSchema, PaginatedResponseSchema,
@@ -20,7 +21,7 @@ struct Page(u32);
 #[nutype(validate(greater_or_equal = 1, less_or_equal = 100))]
 struct Size(u32);
 
-#[derive(TypedBuilder)]
+#[derive(Clone, TypedBuilder)]
 pub struct GetAllCharactersRequest {
     #[builder(default = 1)]
     page: u32,
@@ -41,15 +42,15 @@ pub fn get_all_characters(
         .map_err(|e| crate::Error::InvalidInput(e.to_string()))?
         .into_inner();
 
-    let mut query = Vec::new();
-    if let Some(sort) = sort {
-        query.push(format!("sort={sort}"));
-    }
-    let path = format!("/characters/?page={page}&size={size}&{}", query.join("&"));
+    let path = Query::new("/characters/")
+        .param("page", page)
+        .param("size", size)
+        .optional("sort", sort)
+        .build()?;
 
     Ok(EncodedRequest {
         method: Method::GET,
-        path: PathAndQuery::from_str(&path)?,
+        path,
         headers: HeaderMap::from_iter([ACCEPT_JSON]),
         content: Vec::new(),
         rate_limit: DATA_RATE_LIMIT,
@@ -61,11 +62,28 @@ impl<'de> ParseResponse<'de> for EncodedRequest<GetAllCharactersRequest> {
     type Response = PaginatedResponseSchema<CharacterSchema>;
 }
 
+impl PaginatedRequest for GetAllCharactersRequest {
+    fn page(&self) -> u32 {
+        self.page
+    }
+
+    fn set_page(&mut self, page: u32) {
+        self.page = page;
+    }
+
+    fn encode(self) -> Result<EncodedRequest<Self>, crate::Error> {
+        get_all_characters(self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use proptest::prelude::*;
 
-    use crate::schemas::BearerToken;
+    use crate::{
+        schemas::{BearerToken, CraftSkillSchema},
+        test_support::query_params,
+    };
 
     proptest! {
         #[test]
@@ -79,5 +97,27 @@ mod tests {
                 .build();
             assert!(super::get_all_characters(request).is_ok());
         }
+
+        #[test]
+        fn get_all_characters_should_encode_filters(
+            page in 1u32..=u32::MAX,
+            size in 1u32..=100,
+            sort in proptest::option::of(any::<CraftSkillSchema>()),
+        ) {
+            let request = super::GetAllCharactersRequest::builder()
+                .page(page)
+                .size(size)
+                .sort(sort.clone())
+                .build();
+            let path = super::get_all_characters(request).unwrap().path;
+
+            let mut expected = vec![
+                ("page".to_string(), page.to_string()),
+                ("size".to_string(), size.to_string()),
+            ];
+            expected.extend(sort.map(|sort| ("sort".to_string(), sort.to_string())));
+            prop_assert_eq!(path.path(), "/characters/");
+            prop_assert_eq!(query_params(&path), expected);
+        }
     }
 }
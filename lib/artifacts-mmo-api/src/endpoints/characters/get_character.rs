@@ -12,7 +12,7 @@ use crate::{
         BearerToken, CharacterSchema, CraftSkillSchema, MonsterSchema, PaginatedResponseSchema,
         ResourceSchema, ResponseSchema, SkillSchema,
     },
-    EncodedRequest, ParseResponse,
+    EncodedRequest, ParseResponse, StatusErrors,
 };
 
 #[nutype(validate(regex = "^[a-zA-Z0-9_-]+$"))]
@@ -44,6 +44,9 @@ pub fn get_character(
 
 impl<'de> ParseResponse<'de> for EncodedRequest<GetCharacterRequest> {
     type Response = ResponseSchema<CharacterSchema>;
+    const STATUS_ERRORS: StatusErrors = &[
+        (404, crate::Error::NotFound),
+    ];
 }
 
 #[cfg(test)]
@@ -5,7 +5,8 @@ use nutype::nutype;
 use typed_builder::TypedBuilder;
 
 use crate::{
-    helpers::ACCEPT_JSON,
+    helpers::{Query, ACCEPT_JSON},
+    pagination::PaginatedRequest,
     rate_limits::DATA_RATE_LIMIT,
     schemas::{EventSchema, PaginatedResponseSchema},
     EncodedRequest, ParseResponse,
@@ -16,7 +17,7 @@ struct Page(u32);
 #[nutype(validate(greater_or_equal = 1, less_or_equal = 100))]
 struct Size(u32);
 
-#[derive(TypedBuilder)]
+#[derive(Clone, TypedBuilder)]
 pub struct GetAllEventsRequest {
     #[builder(default = 1)]
     page: u32,
@@ -37,7 +38,10 @@ pub fn get_all_events(
 
     Ok(EncodedRequest {
         method: Method::GET,
-        path: PathAndQuery::from_str(&format!("/events/?page={page}&size={size}"))?,
+        path: Query::new("/events/")
+            .param("page", page)
+            .param("size", size)
+            .build()?,
         headers: HeaderMap::from_iter([ACCEPT_JSON]),
         content: Vec::new(),
         rate_limit: DATA_RATE_LIMIT,
@@ -45,10 +49,24 @@ pub fn get_all_events(
     })
 }
 
-impl<'de> ParseResponse<'de> for GetAllEventsRequest {
+impl<'de> ParseResponse<'de> for EncodedRequest<GetAllEventsRequest> {
     type Response = PaginatedResponseSchema<EventSchema>;
 }
 
+impl PaginatedRequest for GetAllEventsRequest {
+    fn page(&self) -> u32 {
+        self.page
+    }
+
+    fn set_page(&mut self, page: u32) {
+        self.page = page;
+    }
+
+    fn encode(self) -> Result<EncodedRequest<Self>, crate::Error> {
+        get_all_events(self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use proptest::prelude::*;
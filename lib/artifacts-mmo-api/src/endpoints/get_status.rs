@@ -9,7 +9,7 @@ use crate::{
     EncodedRequest, ParseResponse,
 };
 
-struct GetStatusRequest;
+pub struct GetStatusRequest;
 /// SOURCE: <https://api.artifactsmmo.com/docs/#/operations/get_status__get>
 #[tracing::instrument(level = "trace")]
 pub fn get_status() -> EncodedRequest<GetStatusRequest> {
@@ -5,7 +5,8 @@ use nutype::nutype;
 use typed_builder::TypedBuilder;
 
 use crate::{
-    helpers::ACCEPT_JSON,
+    helpers::{Query, ACCEPT_JSON},
+    pagination::PaginatedRequest,
     rate_limits::DATA_RATE_LIMIT,
     schemas::{GEItemSchema, PaginatedResponseSchema, ResponseSchema},
     EncodedRequest, ParseResponse,
@@ -16,8 +17,8 @@ struct Page(u32);
 #[nutype(validate(greater_or_equal = 1, less_or_equal = 100))]
 struct Size(u32);
 
-#[derive(TypedBuilder)]
-struct GetAllGEItemsRequest {
+#[derive(Clone, TypedBuilder)]
+pub struct GetAllGEItemsRequest {
     #[builder(default = 1)]
     page: u32,
     #[builder(default = 50)]
@@ -37,7 +38,10 @@ pub fn get_all_ge_items(
 
     Ok(EncodedRequest {
         method: Method::GET,
-        path: PathAndQuery::from_str(&format!("/ge/?page={page}&size={size}"))?,
+        path: Query::new("/ge/")
+            .param("page", page)
+            .param("size", size)
+            .build()?,
         headers: HeaderMap::from_iter([ACCEPT_JSON]),
         content: Vec::new(),
         rate_limit: DATA_RATE_LIMIT,
@@ -49,6 +53,20 @@ impl<'de> ParseResponse<'de> for EncodedRequest<GetAllGEItemsRequest> {
     type Response = PaginatedResponseSchema<GEItemSchema>;
 }
 
+impl PaginatedRequest for GetAllGEItemsRequest {
+    fn page(&self) -> u32 {
+        self.page
+    }
+
+    fn set_page(&mut self, page: u32) {
+        self.page = page;
+    }
+
+    fn encode(self) -> Result<EncodedRequest<Self>, crate::Error> {
+        get_all_ge_items(self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use proptest::prelude::*;
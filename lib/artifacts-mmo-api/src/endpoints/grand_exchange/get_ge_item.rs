@@ -8,14 +8,14 @@ use crate::{
     helpers::ACCEPT_JSON,
     rate_limits::DATA_RATE_LIMIT,
     schemas::{GEItemSchema, PaginatedResponseSchema, ResponseSchema},
-    EncodedRequest, ParseResponse,
+    EncodedRequest, ParseResponse, StatusErrors,
 };
 
 #[nutype(validate(regex = "^[a-zA-Z0-9_-]+$"))]
 struct Code(String);
 
 #[derive(TypedBuilder)]
-struct GetGEItemRequest {
+pub struct GetGEItemRequest {
     code: String,
 }
 /// SOURCE: <https://api.artifactsmmo.com/docs/#/operations/get_ge_item_ge__code__get>
@@ -39,6 +39,9 @@ pub fn get_ge_item(
 
 impl<'de> ParseResponse<'de> for EncodedRequest<GetGEItemRequest> {
     type Response = ResponseSchema<GEItemSchema>;
+    const STATUS_ERRORS: StatusErrors = &[
+        (404, crate::Error::NotFound),
+    ];
 }
 
 #[cfg(test)]
@@ -5,7 +5,8 @@ use nutype::nutype;
 use typed_builder::TypedBuilder;
 
 use crate::{
-    helpers::ACCEPT_JSON,
+    helpers::{Query, ACCEPT_JSON},
+    pagination::PaginatedRequest,
     rate_limits::DATA_RATE_LIMIT,
     schemas::{
         CraftSkillSchema, ItemSchema, ItemTypeSchema, MonsterSchema, PaginatedResponseSchema,
@@ -21,8 +22,8 @@ struct Size(u32);
 #[nutype(validate(regex = "^[a-zA-Z0-9_-]+$"))]
 struct CraftMaterial(String);
 
-#[derive(TypedBuilder)]
-struct GetAllItemsRequest {
+#[derive(Clone, TypedBuilder)]
+pub struct GetAllItemsRequest {
     #[builder(default = 1)]
     page: u32,
     #[builder(default = 50)]
@@ -61,33 +62,24 @@ pub fn get_all_items(
         .map_err(|e| crate::Error::InvalidInput(e.to_string()))?
         .into_inner();
 
-    let mut query = Vec::new();
-    if let Some(craft_material) = craft_material {
-        let craft_material = CraftMaterial::try_new(craft_material)
-            .map_err(|e| crate::Error::InvalidInput(e.to_string()))?
-            .into_inner();
-        query.push(format!("craft_material={craft_material}"));
-    }
-    if let Some(craft_skill) = craft_skill {
-        query.push(format!("craft_skill={craft_skill}"));
-    }
-    if let Some(name) = name {
-        query.push(format!("name={name}"));
-    }
-    if let Some(r#type) = r#type {
-        query.push(format!("type={type}"));
-    }
-    if let Some(max_level) = max_level {
-        query.push(format!("max_level={max_level}"));
-    }
-    if let Some(min_level) = min_level {
-        query.push(format!("min_level={min_level}"));
-    }
-    let path = format!("/items/?page={page}&size={size}&{}", query.join("&"));
+    let craft_material = craft_material
+        .map(|craft_material| CraftMaterial::try_new(craft_material).map(CraftMaterial::into_inner))
+        .transpose()
+        .map_err(|e| crate::Error::InvalidInput(e.to_string()))?;
+    let path = Query::new("/items/")
+        .param("page", page)
+        .param("size", size)
+        .optional("craft_material", craft_material)
+        .optional("craft_skill", craft_skill)
+        .optional("name", name)
+        .optional("type", r#type)
+        .optional("max_level", max_level)
+        .optional("min_level", min_level)
+        .build()?;
 
     Ok(EncodedRequest {
         method: Method::GET,
-        path: PathAndQuery::from_str(&path)?,
+        path,
         headers: HeaderMap::from_iter([ACCEPT_JSON]),
         content: Vec::new(),
         rate_limit: DATA_RATE_LIMIT,
@@ -99,9 +91,26 @@ impl<'de> ParseResponse<'de> for EncodedRequest<GetAllItemsRequest> {
     type Response = PaginatedResponseSchema<ItemSchema>;
 }
 
+impl PaginatedRequest for GetAllItemsRequest {
+    fn page(&self) -> u32 {
+        self.page
+    }
+
+    fn set_page(&mut self, page: u32) {
+        self.page = page;
+    }
+
+    fn encode(self) -> Result<EncodedRequest<Self>, crate::Error> {
+        get_all_items(self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use proptest::prelude::*;
+
+    use crate::test_support::query_params;
+
     proptest! {
         #[test]
         fn get_all_items_should_work_with_valid_input(
@@ -114,5 +123,28 @@ mod tests {
                 .build();
             assert!(super::get_all_items(request).is_ok());
         }
+
+        #[test]
+        fn get_all_items_should_encode_filters(
+            page in 1u32..=u32::MAX,
+            name in ".*",
+            max_level in proptest::option::of(any::<u32>()),
+        ) {
+            let request = super::GetAllItemsRequest::builder()
+                .page(page)
+                .name(name.clone())
+                .max_level(max_level)
+                .build();
+            let path = super::get_all_items(request).unwrap().path;
+
+            let mut expected = vec![
+                ("page".to_string(), page.to_string()),
+                ("size".to_string(), "50".to_string()),
+                ("name".to_string(), name),
+            ];
+            expected.extend(max_level.map(|level| ("max_level".to_string(), level.to_string())));
+            prop_assert_eq!(path.path(), "/items/");
+            prop_assert_eq!(query_params(&path), expected);
+        }
     }
 }
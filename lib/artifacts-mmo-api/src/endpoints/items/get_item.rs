@@ -11,14 +11,14 @@ use crate::{
         CraftSkillSchema, ItemSchema, ItemTypeSchema, MonsterSchema, PaginatedResponseSchema,
         ResourceSchema, ResponseSchema, SingleItemSchema, SkillSchema,
     },
-    EncodedRequest, ParseResponse,
+    EncodedRequest, ParseResponse, StatusErrors,
 };
 
 #[nutype(validate(regex = "^[a-zA-Z0-9_-]+$"))]
 struct Code(String);
 
 #[derive(TypedBuilder)]
-struct GetItemRequest {
+pub struct GetItemRequest {
     #[builder(setter(into))]
     code: String,
 }
@@ -43,6 +43,9 @@ pub fn get_item(
 
 impl<'de> ParseResponse<'de> for EncodedRequest<GetItemRequest> {
     type Response = ResponseSchema<SingleItemSchema>;
+    const STATUS_ERRORS: StatusErrors = &[
+        (404, crate::Error::NotFound),
+    ];
 }
 
 #[cfg(test)]
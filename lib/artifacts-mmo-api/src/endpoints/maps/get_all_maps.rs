@@ -5,7 +5,8 @@ use nutype::nutype;
 use typed_builder::TypedBuilder;
 
 use crate::{
-    helpers::ACCEPT_JSON,
+    helpers::{Query, ACCEPT_JSON},
+    pagination::PaginatedRequest,
     rate_limits::DATA_RATE_LIMIT,
     schemas::{
         MapContentTypeSchema, MapSchema, MonsterSchema, PaginatedResponseSchema, ResourceSchema,
@@ -21,8 +22,8 @@ struct Size(u32);
 #[nutype(validate(regex = "^[a-zA-Z0-9_-]+$"))]
 struct ContentCode(String);
 
-#[derive(TypedBuilder)]
-struct GetAllMapsRequest {
+#[derive(Clone, TypedBuilder)]
+pub struct GetAllMapsRequest {
     #[builder(default = 1)]
     page: u32,
     #[builder(default = 50)]
@@ -49,21 +50,20 @@ pub fn get_all_maps(
         .map_err(|e| crate::Error::InvalidInput(e.to_string()))?
         .into_inner();
 
-    let mut query = Vec::new();
-    if let Some(content_code) = content_code {
-        let content_code = ContentCode::try_new(content_code)
-            .map_err(|e| crate::Error::InvalidInput(e.to_string()))?
-            .into_inner();
-        query.push(format!("content_code={content_code}"));
-    }
-    if let Some(content_type) = content_type {
-        query.push(format!("content_type={content_type}"));
-    }
-    let path = format!("/maps/?page={page}&size={size}&{}", query.join("&"));
+    let content_code = content_code
+        .map(|content_code| ContentCode::try_new(content_code).map(ContentCode::into_inner))
+        .transpose()
+        .map_err(|e| crate::Error::InvalidInput(e.to_string()))?;
+    let path = Query::new("/maps/")
+        .param("page", page)
+        .param("size", size)
+        .optional("content_code", content_code)
+        .optional("content_type", content_type)
+        .build()?;
 
     Ok(EncodedRequest {
         method: Method::GET,
-        path: PathAndQuery::from_str(&path)?,
+        path,
         headers: HeaderMap::from_iter([ACCEPT_JSON]),
         content: Vec::new(),
         rate_limit: DATA_RATE_LIMIT,
@@ -72,15 +72,32 @@ pub fn get_all_maps(
 }
 
 impl<'de> ParseResponse<'de> for EncodedRequest<GetAllMapsRequest> {
-    type Response = PaginatedResponseSchema<MonsterSchema>;
+    type Response = PaginatedResponseSchema<MapSchema>;
+}
+
+impl PaginatedRequest for GetAllMapsRequest {
+    fn page(&self) -> u32 {
+        self.page
+    }
+
+    fn set_page(&mut self, page: u32) {
+        self.page = page;
+    }
+
+    fn encode(self) -> Result<EncodedRequest<Self>, crate::Error> {
+        get_all_maps(self)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use proptest::prelude::*;
+
+    use crate::{schemas::MapContentTypeSchema, test_support::query_params};
+
     proptest! {
         #[test]
-        fn get_all_monsters_should_work_with_valid_input(
+        fn get_all_maps_should_work_with_valid_input(
             page in 1u32..=u32::MAX,
             size in 1u32..=50,
         ) {
@@ -90,5 +107,32 @@ mod tests {
                 .build();
             assert!(super::get_all_maps(request).is_ok());
         }
+
+        #[test]
+        fn get_all_maps_should_encode_filters(
+            page in 1u32..=u32::MAX,
+            size in 1u32..=100,
+            content_code in proptest::option::of("[a-zA-Z0-9_-]+"),
+            content_type in proptest::option::of(any::<MapContentTypeSchema>()),
+        ) {
+            let request = super::GetAllMapsRequest::builder()
+                .page(page)
+                .size(size)
+                .content_code(content_code.clone())
+                .content_type(content_type.clone())
+                .build();
+            let path = super::get_all_maps(request).unwrap().path;
+
+            let mut expected = vec![
+                ("page".to_string(), page.to_string()),
+                ("size".to_string(), size.to_string()),
+            ];
+            expected.extend(content_code.map(|code| ("content_code".to_string(), code)));
+            expected.extend(
+                content_type.map(|r#type| ("content_type".to_string(), r#type.to_string())),
+            );
+            prop_assert_eq!(path.path(), "/maps/");
+            prop_assert_eq!(query_params(&path), expected);
+        }
     }
 }
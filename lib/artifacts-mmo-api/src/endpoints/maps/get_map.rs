@@ -11,14 +11,22 @@ use crate::{
         MapContentTypeSchema, MapSchema, MonsterSchema, PaginatedResponseSchema, ResourceSchema,
         ResponseSchema, SkillSchema,
     },
-    EncodedRequest, ParseResponse,
+    world::Position,
+    EncodedRequest, ParseResponse, StatusErrors,
 };
 
 #[derive(TypedBuilder)]
-struct GetMapRequest {
-    x: u32,
-    y: u32,
+pub struct GetMapRequest {
+    x: i32,
+    y: i32,
 }
+
+impl From<Position> for GetMapRequest {
+    fn from(Position { x, y }: Position) -> Self {
+        Self { x, y }
+    }
+}
+
 /// SOURCE: <https://api.artifactsmmo.com/docs/#/operations/get_map_maps__x___y__get>
 #[tracing::instrument(level = "trace")]
 pub fn get_map(
@@ -26,7 +34,7 @@ pub fn get_map(
 ) -> Result<EncodedRequest<GetMapRequest>, crate::Error> {
     Ok(EncodedRequest {
         method: Method::GET,
-        path: PathAndQuery::from_str(&format!("/monsters/{x}/{y}"))?,
+        path: PathAndQuery::from_str(&format!("/maps/{x}/{y}"))?,
         headers: HeaderMap::from_iter([ACCEPT_JSON]),
         content: Vec::new(),
         rate_limit: DATA_RATE_LIMIT,
@@ -36,6 +44,9 @@ pub fn get_map(
 
 impl<'de> ParseResponse<'de> for EncodedRequest<GetMapRequest> {
     type Response = ResponseSchema<MapSchema>;
+    const STATUS_ERRORS: StatusErrors = &[
+        (404, crate::Error::NotFound),
+    ];
 }
 
 #[cfg(test)]
@@ -44,8 +55,8 @@ mod tests {
     proptest! {
         #[test]
         fn get_map_should_work_with_valid_input(
-            x in 1u32..=u32::MAX,
-            y in 1u32..=u32::MAX,
+            x in i32::MIN..=i32::MAX,
+            y in i32::MIN..=i32::MAX,
         ) {
             let request = super::GetMapRequest::builder()
                 .x(x)
@@ -23,3 +23,17 @@ pub_mod_use! {
     accounts,
     token
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::test_support::endpoints;
+
+    #[test]
+    fn endpoints_should_match_the_api() {
+        for endpoint in endpoints() {
+            let (method, path) = &endpoint.expected;
+            assert_eq!(endpoint.method, method, "method of {}", endpoint.name);
+            assert_eq!(endpoint.path.as_str(), *path, "path of {}", endpoint.name);
+        }
+    }
+}
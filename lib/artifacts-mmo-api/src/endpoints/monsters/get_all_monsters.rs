@@ -5,7 +5,8 @@ use nutype::nutype;
 use typed_builder::TypedBuilder;
 
 use crate::{
-    helpers::ACCEPT_JSON,
+    helpers::{Query, ACCEPT_JSON},
+    pagination::PaginatedRequest,
     rate_limits::DATA_RATE_LIMIT,
     schemas::{
         MonsterSchema, PaginatedResponseSchema, ResourceSchema, ResponseSchema, SkillSchema,
@@ -20,8 +21,8 @@ struct Size(u32);
 #[nutype(validate(regex = "^[a-zA-Z0-9_-]+$"))]
 struct Drop(String);
 
-#[derive(TypedBuilder)]
-struct GetAllMonstersRequest {
+#[derive(Clone, TypedBuilder)]
+pub struct GetAllMonstersRequest {
     #[builder(default = 1)]
     page: u32,
     #[builder(default = 50)]
@@ -51,24 +52,21 @@ pub fn get_all_monsters(
         .map_err(|e| crate::Error::InvalidInput(e.to_string()))?
         .into_inner();
 
-    let mut query = Vec::new();
-    if let Some(drop) = drop {
-        let drop = Drop::try_new(drop)
-            .map_err(|e| crate::Error::InvalidInput(e.to_string()))?
-            .into_inner();
-        query.push(format!("drop={drop}"));
-    }
-    if let Some(max_level) = max_level {
-        query.push(format!("max_level={max_level}"));
-    }
-    if let Some(min_level) = min_level {
-        query.push(format!("min_level={min_level}"));
-    }
-    let path = format!("/monsters/?page={page}&size={size}&{}", query.join("&"));
+    let drop = drop
+        .map(|drop| Drop::try_new(drop).map(Drop::into_inner))
+        .transpose()
+        .map_err(|e| crate::Error::InvalidInput(e.to_string()))?;
+    let path = Query::new("/monsters/")
+        .param("page", page)
+        .param("size", size)
+        .optional("drop", drop)
+        .optional("max_level", max_level)
+        .optional("min_level", min_level)
+        .build()?;
 
     Ok(EncodedRequest {
         method: Method::GET,
-        path: PathAndQuery::from_str(&path)?,
+        path,
         headers: HeaderMap::from_iter([ACCEPT_JSON]),
         content: Vec::new(),
         rate_limit: DATA_RATE_LIMIT,
@@ -80,9 +78,26 @@ impl<'de> ParseResponse<'de> for EncodedRequest<GetAllMonstersRequest> {
     type Response = PaginatedResponseSchema<MonsterSchema>;
 }
 
+impl PaginatedRequest for GetAllMonstersRequest {
+    fn page(&self) -> u32 {
+        self.page
+    }
+
+    fn set_page(&mut self, page: u32) {
+        self.page = page;
+    }
+
+    fn encode(self) -> Result<EncodedRequest<Self>, crate::Error> {
+        get_all_monsters(self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use proptest::prelude::*;
+
+    use crate::test_support::query_params;
+
     proptest! {
         #[test]
         fn get_all_monsters_should_work_with_valid_input(
@@ -95,5 +110,33 @@ mod tests {
                 .build();
             assert!(super::get_all_monsters(request).is_ok());
         }
+
+        #[test]
+        fn get_all_monsters_should_encode_filters(
+            page in 1u32..=u32::MAX,
+            size in 1u32..=100,
+            drop in proptest::option::of("[a-zA-Z0-9_-]+"),
+            max_level in proptest::option::of(any::<u32>()),
+            min_level in proptest::option::of(any::<u32>()),
+        ) {
+            let request = super::GetAllMonstersRequest::builder()
+                .page(page)
+                .size(size)
+                .drop(drop.clone())
+                .max_level(max_level)
+                .min_level(min_level)
+                .build();
+            let path = super::get_all_monsters(request).unwrap().path;
+
+            let mut expected = vec![
+                ("page".to_string(), page.to_string()),
+                ("size".to_string(), size.to_string()),
+            ];
+            expected.extend(drop.map(|code| ("drop".to_string(), code)));
+            expected.extend(max_level.map(|level| ("max_level".to_string(), level.to_string())));
+            expected.extend(min_level.map(|level| ("min_level".to_string(), level.to_string())));
+            prop_assert_eq!(path.path(), "/monsters/");
+            prop_assert_eq!(query_params(&path), expected);
+        }
     }
 }
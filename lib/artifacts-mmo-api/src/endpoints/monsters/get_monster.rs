@@ -10,14 +10,14 @@ use crate::{
     schemas::{
         MonsterSchema, PaginatedResponseSchema, ResourceSchema, ResponseSchema, SkillSchema,
     },
-    EncodedRequest, ParseResponse,
+    EncodedRequest, ParseResponse, StatusErrors,
 };
 
 #[nutype(validate(regex = "^[a-zA-Z0-9_-]+$"))]
 struct Code(String);
 
 #[derive(TypedBuilder)]
-struct GetMonsterRequest {
+pub struct GetMonsterRequest {
     #[builder(setter(into))]
     code: String,
 }
@@ -42,6 +42,9 @@ pub fn get_monster(
 
 impl<'de> ParseResponse<'de> for EncodedRequest<GetMonsterRequest> {
     type Response = ResponseSchema<MonsterSchema>;
+    const STATUS_ERRORS: StatusErrors = &[
+        (404, crate::Error::NotFound),
+    ];
 }
 
 #[cfg(test)]
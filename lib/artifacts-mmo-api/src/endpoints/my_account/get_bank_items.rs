@@ -6,13 +6,14 @@ use serde_json::json;
 use typed_builder::TypedBuilder;
 
 use crate::{
-    helpers::ACCEPT_JSON,
+    helpers::{Query, ACCEPT_JSON},
+    pagination::PaginatedRequest,
     rate_limits::DATA_RATE_LIMIT,
     schemas::{
         BearerToken, GoldSchema, MessageSchema, PaginatedResponseSchema, ResponseSchema,
         SimpleItemSchema,
     },
-    EncodedRequest, ParseResponse,
+    EncodedRequest, ParseResponse, StatusErrors,
 };
 
 #[nutype(validate(greater_or_equal = 1))]
@@ -22,7 +23,7 @@ struct Size(u32);
 #[nutype(validate(regex = "^[a-zA-Z0-9_-]+$"))]
 struct ItemCode(String);
 
-#[derive(TypedBuilder)]
+#[derive(Clone, TypedBuilder)]
 pub struct GetBankItemsRequest {
     bearer_token: BearerToken,
     #[builder(default = 1)]
@@ -48,18 +49,15 @@ pub fn get_bank_items(
         .map_err(|e| crate::Error::InvalidInput(e.to_string()))?
         .into_inner();
 
-    let mut query = Vec::new();
-    if let Some(item_code) = item_code {
-        query.push(format!("item_code={item_code}"));
-    }
-    let path = format!(
-        "/my/bank/items/?page={page}&size={size}&{}",
-        query.join("&")
-    );
+    let path = Query::new("/my/bank/items/")
+        .param("page", page)
+        .param("size", size)
+        .optional("item_code", item_code)
+        .build()?;
 
     Ok(EncodedRequest {
         method: Method::GET,
-        path: PathAndQuery::from_str(&path)?,
+        path,
         headers: HeaderMap::from_iter([
             ACCEPT_JSON,
             (
@@ -75,13 +73,30 @@ pub fn get_bank_items(
 
 impl<'de> ParseResponse<'de> for EncodedRequest<GetBankItemsRequest> {
     type Response = PaginatedResponseSchema<SimpleItemSchema>;
+    const STATUS_ERRORS: StatusErrors = &[
+        (404, crate::Error::NotFound),
+    ];
+}
+
+impl PaginatedRequest for GetBankItemsRequest {
+    fn page(&self) -> u32 {
+        self.page
+    }
+
+    fn set_page(&mut self, page: u32) {
+        self.page = page;
+    }
+
+    fn encode(self) -> Result<EncodedRequest<Self>, crate::Error> {
+        get_bank_items(self)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use proptest::prelude::*;
 
-    use crate::schemas::BearerToken;
+    use crate::{schemas::BearerToken, test_support::query_params};
 
     proptest! {
         #[test]
@@ -96,5 +111,28 @@ mod tests {
                 .build();
             assert!(super::get_bank_items(request).is_ok());
         }
+
+        #[test]
+        fn get_bank_items_should_encode_filters(
+            page in 1u32..=u32::MAX,
+            size in 1u32..=100,
+            item_code in proptest::option::of(".*"),
+        ) {
+            let request = super::GetBankItemsRequest::builder()
+                .bearer_token(BearerToken("a valid token".to_string()))
+                .page(page)
+                .size(size)
+                .item_code(item_code.clone())
+                .build();
+            let path = super::get_bank_items(request).unwrap().path;
+
+            let mut expected = vec![
+                ("page".to_string(), page.to_string()),
+                ("size".to_string(), size.to_string()),
+            ];
+            expected.extend(item_code.map(|code| ("item_code".to_string(), code)));
+            prop_assert_eq!(path.path(), "/my/bank/items/");
+            prop_assert_eq!(query_params(&path), expected);
+        }
     }
 }
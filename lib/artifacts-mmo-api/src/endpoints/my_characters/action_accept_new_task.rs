@@ -0,0 +1,76 @@
+use std::{marker::PhantomData, str::FromStr};
+
+use http::{header::AUTHORIZATION, uri::PathAndQuery, HeaderMap, HeaderValue, Method};
+use nutype::nutype;
+use typed_builder::TypedBuilder;
+
+use crate::{
+    helpers::{ACCEPT_JSON, CONTENT_TYPE_JSON},
+    rate_limits::ACTIONS_RATE_LIMIT,
+    schemas::{BearerToken, ResponseSchema, TaskDataSchema},
+    EncodedRequest, ParseResponse, StatusErrors,
+};
+
+#[nutype(validate(regex = "^[a-zA-Z0-9_-]+$"))]
+struct Name(String);
+
+#[derive(TypedBuilder)]
+pub struct ActionAcceptNewTaskRequest {
+    bearer_token: BearerToken,
+    #[builder(setter(into))]
+    name: String,
+}
+/// SOURCE: <https://api.artifactsmmo.com/docs/#/operations/action_accept_new_task_my__name__action_task_new_post>
+pub fn action_accept_new_task(
+    ActionAcceptNewTaskRequest { bearer_token, name }: ActionAcceptNewTaskRequest,
+) -> Result<EncodedRequest<ActionAcceptNewTaskRequest>, crate::Error> {
+    let name = Name::try_new(name)
+        .map_err(|e| crate::Error::InvalidInput(e.to_string()))?
+        .into_inner();
+
+    Ok(EncodedRequest {
+        method: Method::POST,
+        path: PathAndQuery::from_str(&format!("/my/{name}/action/task/new"))?,
+        headers: HeaderMap::from_iter([
+            ACCEPT_JSON,
+            CONTENT_TYPE_JSON,
+            (
+                AUTHORIZATION,
+                HeaderValue::from_str(&format!("Bearer {}", bearer_token.0))?,
+            ),
+        ]),
+        content: Vec::new(),
+        rate_limit: ACTIONS_RATE_LIMIT,
+        marker: PhantomData,
+    })
+}
+
+impl<'de> ParseResponse<'de> for EncodedRequest<ActionAcceptNewTaskRequest> {
+    type Response = ResponseSchema<TaskDataSchema>;
+    const STATUS_ERRORS: StatusErrors = &[
+        (486, crate::Error::ActionInProgress),
+        (489, crate::Error::TaskAlreadyAssigned),
+        (498, crate::Error::CharacterNotFound),
+        (499, crate::Error::CharacterInCooldown),
+        (598, crate::Error::ContentNotFoundOnMap),
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::schemas::BearerToken;
+    proptest! {
+        #[test]
+        fn action_accept_new_task_should_not_panic_with_valid_input(
+            name in "[a-zA-Z0-9_-]+",
+        ) {
+            let request = super::ActionAcceptNewTaskRequest::builder()
+                .bearer_token(BearerToken("valid token".to_string()))
+                .name(name)
+                .build();
+            assert!(super::action_accept_new_task(request).is_ok());
+        }
+    }
+}
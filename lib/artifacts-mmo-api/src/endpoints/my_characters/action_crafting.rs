@@ -0,0 +1,106 @@
+use std::{marker::PhantomData, str::FromStr};
+
+use http::{header::AUTHORIZATION, uri::PathAndQuery, HeaderMap, HeaderValue, Method};
+use nutype::nutype;
+use serde_json::json;
+use typed_builder::TypedBuilder;
+
+use crate::{
+    helpers::{ACCEPT_JSON, CONTENT_TYPE_JSON},
+    rate_limits::ACTIONS_RATE_LIMIT,
+    schemas::{BearerToken, ResponseSchema, SkillDataSchema},
+    EncodedRequest, ParseResponse, StatusErrors,
+};
+
+#[nutype(validate(regex = "^[a-zA-Z0-9_-]+$"))]
+struct Name(String);
+#[nutype(validate(regex = "^[a-zA-Z0-9_-]+$"))]
+struct Code(String);
+#[nutype(validate(greater_or_equal = 1))]
+struct Quantity(u32);
+
+#[derive(TypedBuilder)]
+pub struct ActionCraftingRequest {
+    bearer_token: BearerToken,
+    #[builder(setter(into))]
+    name: String,
+    #[builder(setter(into))]
+    code: String,
+    #[builder(default = 1)]
+    quantity: u32,
+}
+/// SOURCE: <https://api.artifactsmmo.com/docs/#/operations/action_crafting_my__name__action_crafting_post>
+pub fn action_crafting(
+    ActionCraftingRequest {
+        bearer_token,
+        name,
+        code,
+        quantity,
+    }: ActionCraftingRequest,
+) -> Result<EncodedRequest<ActionCraftingRequest>, crate::Error> {
+    let name = Name::try_new(name)
+        .map_err(|e| crate::Error::InvalidInput(e.to_string()))?
+        .into_inner();
+    let code = Code::try_new(code)
+        .map_err(|e| crate::Error::InvalidInput(e.to_string()))?
+        .into_inner();
+    let quantity = Quantity::try_new(quantity)
+        .map_err(|e| crate::Error::InvalidInput(e.to_string()))?
+        .into_inner();
+
+    Ok(EncodedRequest {
+        method: Method::POST,
+        path: PathAndQuery::from_str(&format!("/my/{name}/action/crafting"))?,
+        headers: HeaderMap::from_iter([
+            ACCEPT_JSON,
+            CONTENT_TYPE_JSON,
+            (
+                AUTHORIZATION,
+                HeaderValue::from_str(&format!("Bearer {}", bearer_token.0))?,
+            ),
+        ]),
+        content: serde_json::to_vec(&json!({
+            "code": code,
+            "quantity": quantity
+        }))?,
+        rate_limit: ACTIONS_RATE_LIMIT,
+        marker: PhantomData,
+    })
+}
+
+impl<'de> ParseResponse<'de> for EncodedRequest<ActionCraftingRequest> {
+    type Response = ResponseSchema<SkillDataSchema>;
+    const STATUS_ERRORS: StatusErrors = &[
+        (404, crate::Error::NotFound),
+        (478, crate::Error::MissingItem),
+        (486, crate::Error::ActionInProgress),
+        (493, crate::Error::SkillLevelTooLow),
+        (497, crate::Error::InventoryFull),
+        (498, crate::Error::CharacterNotFound),
+        (499, crate::Error::CharacterInCooldown),
+        (598, crate::Error::ContentNotFoundOnMap),
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::schemas::BearerToken;
+    proptest! {
+        #[test]
+        fn action_crafting_should_not_panic_with_valid_input(
+            name in "[a-zA-Z0-9_-]+",
+            code in "[a-zA-Z0-9_-]+",
+            quantity in 1u32..=u32::MAX,
+        ) {
+            let request = super::ActionCraftingRequest::builder()
+                .bearer_token(BearerToken("valid token".to_string()))
+                .name(name)
+                .code(code)
+                .quantity(quantity)
+                .build();
+            assert!(super::action_crafting(request).is_ok());
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use std::{marker::PhantomData, str::FromStr};
+
+use http::{header::AUTHORIZATION, uri::PathAndQuery, HeaderMap, HeaderValue, Method};
+use nutype::nutype;
+use serde_json::json;
+use typed_builder::TypedBuilder;
+
+use crate::{
+    helpers::{ACCEPT_JSON, CONTENT_TYPE_JSON},
+    rate_limits::ACTIONS_RATE_LIMIT,
+    schemas::{BearerToken, GoldTransactionSchema, ResponseSchema},
+    EncodedRequest, ParseResponse, StatusErrors,
+};
+
+#[nutype(validate(regex = "^[a-zA-Z0-9_-]+$"))]
+struct Name(String);
+#[nutype(validate(greater_or_equal = 1))]
+struct Quantity(u32);
+
+#[derive(TypedBuilder)]
+pub struct ActionDepositBankGoldRequest {
+    bearer_token: BearerToken,
+    #[builder(setter(into))]
+    name: String,
+    quantity: u32,
+}
+/// SOURCE: <https://api.artifactsmmo.com/docs/#/operations/action_deposit_bank_gold_my__name__action_bank_deposit_gold_post>
+pub fn action_deposit_bank_gold(
+    ActionDepositBankGoldRequest {
+        bearer_token,
+        name,
+        quantity,
+    }: ActionDepositBankGoldRequest,
+) -> Result<EncodedRequest<ActionDepositBankGoldRequest>, crate::Error> {
+    let name = Name::try_new(name)
+        .map_err(|e| crate::Error::InvalidInput(e.to_string()))?
+        .into_inner();
+    let quantity = Quantity::try_new(quantity)
+        .map_err(|e| crate::Error::InvalidInput(e.to_string()))?
+        .into_inner();
+
+    Ok(EncodedRequest {
+        method: Method::POST,
+        path: PathAndQuery::from_str(&format!("/my/{name}/action/bank/deposit/gold"))?,
+        headers: HeaderMap::from_iter([
+            ACCEPT_JSON,
+            CONTENT_TYPE_JSON,
+            (
+                AUTHORIZATION,
+                HeaderValue::from_str(&format!("Bearer {}", bearer_token.0))?,
+            ),
+        ]),
+        content: serde_json::to_vec(&json!({
+            "quantity": quantity
+        }))?,
+        rate_limit: ACTIONS_RATE_LIMIT,
+        marker: PhantomData,
+    })
+}
+
+impl<'de> ParseResponse<'de> for EncodedRequest<ActionDepositBankGoldRequest> {
+    type Response = ResponseSchema<GoldTransactionSchema>;
+    const STATUS_ERRORS: StatusErrors = &[
+        (461, crate::Error::TransactionInProgress),
+        (486, crate::Error::ActionInProgress),
+        (492, crate::Error::InsufficientGold),
+        (498, crate::Error::CharacterNotFound),
+        (499, crate::Error::CharacterInCooldown),
+        (598, crate::Error::ContentNotFoundOnMap),
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::schemas::BearerToken;
+    proptest! {
+        #[test]
+        fn action_deposit_bank_gold_should_not_panic_with_valid_input(
+            name in "[a-zA-Z0-9_-]+",
+            quantity in 1u32..=u32::MAX,
+        ) {
+            let request = super::ActionDepositBankGoldRequest::builder()
+                .bearer_token(BearerToken("valid token".to_string()))
+                .name(name)
+                .quantity(quantity)
+                .build();
+            assert!(super::action_deposit_bank_gold(request).is_ok());
+        }
+
+        #[test]
+        fn action_deposit_bank_gold_should_fail_with_zero_quantity(
+            name in "[a-zA-Z0-9_-]+",
+        ) {
+            let request = super::ActionDepositBankGoldRequest::builder()
+                .bearer_token(BearerToken("valid token".to_string()))
+                .name(name)
+                .quantity(0)
+                .build();
+            assert!(super::action_deposit_bank_gold(request).is_err());
+        }
+    }
+}
@@ -12,7 +12,7 @@ use crate::{
         BearerToken, CharacterMovementDataSchema, EquipRequestSchema, ResponseSchema,
         SlotTypeSchema,
     },
-    EncodedRequest, ParseResponse,
+    EncodedRequest, ParseResponse, StatusErrors,
 };
 
 #[nutype(validate(regex = "^[a-zA-Z0-9_-]+$"))]
@@ -20,7 +20,7 @@ struct Name(String);
 #[nutype(validate(regex = "^[a-zA-Z0-9_-]+$"))]
 struct Code(String);
 
-#[derive(TypedBuilder)]
+#[derive(Debug, Clone, TypedBuilder)]
 pub struct ActionEquipItemRequest {
     bearer_token: BearerToken,
     #[builder(setter(into))]
@@ -60,6 +60,16 @@ pub fn action_equip_item(
 
 impl<'de> ParseResponse<'de> for EncodedRequest<ActionEquipItemRequest> {
     type Response = ResponseSchema<EquipRequestSchema>;
+    const STATUS_ERRORS: StatusErrors = &[
+        (404, crate::Error::NotFound),
+        (478, crate::Error::MissingItem),
+        (485, crate::Error::ItemAlreadyEquipped),
+        (486, crate::Error::ActionInProgress),
+        (491, crate::Error::SlotNotEmpty),
+        (496, crate::Error::LevelTooLow),
+        (498, crate::Error::CharacterNotFound),
+        (499, crate::Error::CharacterInCooldown),
+    ];
 }
 
 #[cfg(test)]
@@ -12,7 +12,7 @@ use crate::{
         BearerToken, CharacterFightDataSchema, CharacterMovementDataSchema, EquipRequestSchema,
         ResponseSchema, SlotTypeSchema,
     },
-    EncodedRequest, ParseResponse,
+    EncodedRequest, ParseResponse, StatusErrors,
 };
 
 #[nutype(validate(regex = "^[a-zA-Z0-9_-]+$"))]
@@ -47,6 +47,13 @@ pub fn action_fight(
 
 impl<'de> ParseResponse<'de> for EncodedRequest<ActionFightRequest> {
     type Response = ResponseSchema<CharacterFightDataSchema>;
+    const STATUS_ERRORS: StatusErrors = &[
+        (486, crate::Error::ActionInProgress),
+        (497, crate::Error::InventoryFull),
+        (498, crate::Error::CharacterNotFound),
+        (499, crate::Error::CharacterInCooldown),
+        (598, crate::Error::ContentNotFoundOnMap),
+    ];
 }
 
 #[cfg(test)]
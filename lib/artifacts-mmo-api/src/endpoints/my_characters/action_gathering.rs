@@ -0,0 +1,77 @@
+use std::{marker::PhantomData, str::FromStr};
+
+use http::{header::AUTHORIZATION, uri::PathAndQuery, HeaderMap, HeaderValue, Method};
+use nutype::nutype;
+use typed_builder::TypedBuilder;
+
+use crate::{
+    helpers::{ACCEPT_JSON, CONTENT_TYPE_JSON},
+    rate_limits::ACTIONS_RATE_LIMIT,
+    schemas::{BearerToken, ResponseSchema, SkillDataSchema},
+    EncodedRequest, ParseResponse, StatusErrors,
+};
+
+#[nutype(validate(regex = "^[a-zA-Z0-9_-]+$"))]
+struct Name(String);
+
+#[derive(TypedBuilder)]
+pub struct ActionGatheringRequest {
+    bearer_token: BearerToken,
+    #[builder(setter(into))]
+    name: String,
+}
+/// SOURCE: <https://api.artifactsmmo.com/docs/#/operations/action_gathering_my__name__action_gathering_post>
+pub fn action_gathering(
+    ActionGatheringRequest { bearer_token, name }: ActionGatheringRequest,
+) -> Result<EncodedRequest<ActionGatheringRequest>, crate::Error> {
+    let name = Name::try_new(name)
+        .map_err(|e| crate::Error::InvalidInput(e.to_string()))?
+        .into_inner();
+
+    Ok(EncodedRequest {
+        method: Method::POST,
+        path: PathAndQuery::from_str(&format!("/my/{name}/action/gathering"))?,
+        headers: HeaderMap::from_iter([
+            ACCEPT_JSON,
+            CONTENT_TYPE_JSON,
+            (
+                AUTHORIZATION,
+                HeaderValue::from_str(&format!("Bearer {}", bearer_token.0))?,
+            ),
+        ]),
+        content: Vec::new(),
+        rate_limit: ACTIONS_RATE_LIMIT,
+        marker: PhantomData,
+    })
+}
+
+impl<'de> ParseResponse<'de> for EncodedRequest<ActionGatheringRequest> {
+    type Response = ResponseSchema<SkillDataSchema>;
+    const STATUS_ERRORS: StatusErrors = &[
+        (486, crate::Error::ActionInProgress),
+        (493, crate::Error::SkillLevelTooLow),
+        (497, crate::Error::InventoryFull),
+        (498, crate::Error::CharacterNotFound),
+        (499, crate::Error::CharacterInCooldown),
+        (598, crate::Error::ContentNotFoundOnMap),
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::schemas::BearerToken;
+    proptest! {
+        #[test]
+        fn action_gathering_should_not_panic_with_valid_input(
+            name in "[a-zA-Z0-9_-]+",
+        ) {
+            let request = super::ActionGatheringRequest::builder()
+                .bearer_token(BearerToken("valid token".to_string()))
+                .name(name)
+                .build();
+            assert!(super::action_gathering(request).is_ok());
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use std::{marker::PhantomData, str::FromStr};
+
+use http::{header::AUTHORIZATION, uri::PathAndQuery, HeaderMap, HeaderValue, Method};
+use nutype::nutype;
+use serde_json::json;
+use typed_builder::TypedBuilder;
+
+use crate::{
+    helpers::{ACCEPT_JSON, CONTENT_TYPE_JSON},
+    rate_limits::ACTIONS_RATE_LIMIT,
+    schemas::{BearerToken, GEItemSchema, GETransactionListSchema, ResponseSchema},
+    EncodedRequest, ParseResponse, StatusErrors,
+};
+
+#[nutype(validate(regex = "^[a-zA-Z0-9_-]+$"))]
+struct Name(String);
+#[nutype(validate(regex = "^[a-zA-Z0-9_-]+$"))]
+struct Code(String);
+#[nutype(validate(greater_or_equal = 1))]
+struct Quantity(u32);
+#[nutype(validate(greater_or_equal = 1))]
+struct Price(u32);
+
+#[derive(TypedBuilder)]
+pub struct ActionGEBuyItemRequest {
+    bearer_token: BearerToken,
+    #[builder(setter(into))]
+    name: String,
+    #[builder(setter(into))]
+    code: String,
+    quantity: u32,
+    price: u32,
+    /// Last known listing of the item (from `get_ge_item`), used to reject the request before
+    /// sending it if the buy price has moved
+    #[builder(default, setter(strip_option))]
+    price_guard: Option<GEItemSchema>,
+}
+/// SOURCE: <https://api.artifactsmmo.com/docs/#/operations/action_ge_buy_item_my__name__action_ge_buy_post>
+pub fn action_ge_buy_item(
+    ActionGEBuyItemRequest {
+        bearer_token,
+        name,
+        code,
+        quantity,
+        price,
+        price_guard,
+    }: ActionGEBuyItemRequest,
+) -> Result<EncodedRequest<ActionGEBuyItemRequest>, crate::Error> {
+    let name = Name::try_new(name)
+        .map_err(|e| crate::Error::InvalidInput(e.to_string()))?
+        .into_inner();
+    let code = Code::try_new(code)
+        .map_err(|e| crate::Error::InvalidInput(e.to_string()))?
+        .into_inner();
+    let quantity = Quantity::try_new(quantity)
+        .map_err(|e| crate::Error::InvalidInput(e.to_string()))?
+        .into_inner();
+    let price = Price::try_new(price)
+        .map_err(|e| crate::Error::InvalidInput(e.to_string()))?
+        .into_inner();
+
+    if let Some(ge_item) = price_guard {
+        if ge_item.code != code {
+            return Err(crate::Error::InvalidInput(format!(
+                "price guard is for {} but the request is for {code}",
+                ge_item.code
+            )));
+        }
+        if ge_item.buy_price != price {
+            return Err(crate::Error::GEPriceMismatch {
+                code,
+                requested: price,
+                current: ge_item.buy_price,
+            });
+        }
+    }
+
+    Ok(EncodedRequest {
+        method: Method::POST,
+        path: PathAndQuery::from_str(&format!("/my/{name}/action/ge/buy"))?,
+        headers: HeaderMap::from_iter([
+            ACCEPT_JSON,
+            CONTENT_TYPE_JSON,
+            (
+                AUTHORIZATION,
+                HeaderValue::from_str(&format!("Bearer {}", bearer_token.0))?,
+            ),
+        ]),
+        content: serde_json::to_vec(&json!({
+            "code": code,
+            "quantity": quantity,
+            "price": price
+        }))?,
+        rate_limit: ACTIONS_RATE_LIMIT,
+        marker: PhantomData,
+    })
+}
+
+impl<'de> ParseResponse<'de> for EncodedRequest<ActionGEBuyItemRequest> {
+    type Response = ResponseSchema<GETransactionListSchema>;
+    const STATUS_ERRORS: StatusErrors = &[
+        (404, crate::Error::NotFound),
+        (480, crate::Error::GENoStock),
+        (482, crate::Error::GEPriceChanged),
+        (483, crate::Error::TransactionInProgress),
+        (486, crate::Error::ActionInProgress),
+        (492, crate::Error::InsufficientGold),
+        (497, crate::Error::InventoryFull),
+        (498, crate::Error::CharacterNotFound),
+        (499, crate::Error::CharacterInCooldown),
+        (598, crate::Error::ContentNotFoundOnMap),
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::schemas::{BearerToken, GEItemSchema};
+    proptest! {
+        #[test]
+        fn action_ge_buy_item_should_not_panic_with_valid_input(
+            name in "[a-zA-Z0-9_-]+",
+            code in "[a-zA-Z0-9_-]+",
+            quantity in 1u32..=u32::MAX,
+            price in 1u32..=u32::MAX,
+        ) {
+            let request = super::ActionGEBuyItemRequest::builder()
+                .bearer_token(BearerToken("valid token".to_string()))
+                .name(name)
+                .code(code)
+                .quantity(quantity)
+                .price(price)
+                .build();
+            assert!(super::action_ge_buy_item(request).is_ok());
+        }
+
+        #[test]
+        fn action_ge_buy_item_should_reject_moved_price(
+            name in "[a-zA-Z0-9_-]+",
+            code in "[a-zA-Z0-9_-]+",
+            price in 1u32..u32::MAX,
+        ) {
+            let request = super::ActionGEBuyItemRequest::builder()
+                .bearer_token(BearerToken("valid token".to_string()))
+                .name(name)
+                .code(code.clone())
+                .quantity(1)
+                .price(price)
+                .price_guard(GEItemSchema {
+                    code,
+                    stock: 1,
+                    sell_price: price + 1,
+                    buy_price: price + 1,
+                })
+                .build();
+            assert!(matches!(
+                super::action_ge_buy_item(request),
+                Err(crate::Error::GEPriceMismatch { .. })
+            ));
+        }
+    }
+}
@@ -9,7 +9,7 @@ use crate::{
     helpers::{ACCEPT_JSON, CONTENT_TYPE_JSON},
     rate_limits::ACTIONS_RATE_LIMIT,
     schemas::{BearerToken, CharacterMovementDataSchema, ResponseSchema},
-    EncodedRequest, ParseResponse,
+    EncodedRequest, ParseResponse, StatusErrors,
 };
 
 #[nutype(validate(regex = "^[a-zA-Z0-9_-]+$"))]
@@ -20,8 +20,8 @@ pub struct ActionMoveRequest {
     bearer_token: BearerToken,
     #[builder(setter(into))]
     name: String,
-    x: u32,
-    y: u32,
+    x: i32,
+    y: i32,
 }
 
 pub fn action_move(
@@ -54,6 +54,13 @@ pub fn action_move(
 
 impl<'de> ParseResponse<'de> for EncodedRequest<ActionMoveRequest> {
     type Response = ResponseSchema<CharacterMovementDataSchema>;
+    const STATUS_ERRORS: StatusErrors = &[
+        (404, crate::Error::NotFound),
+        (486, crate::Error::ActionInProgress),
+        (490, crate::Error::AlreadyAtDestination),
+        (498, crate::Error::CharacterNotFound),
+        (499, crate::Error::CharacterInCooldown),
+    ];
 }
 
 #[cfg(test)]
@@ -65,8 +72,8 @@ mod tests {
         #[test]
         fn action_move_should_not_panic_with_valid_input(
             name in "[a-zA-Z0-9_-]+",
-            x in 0u32..=u32::MAX,
-            y in 0u32..=u32::MAX
+            x in i32::MIN..=i32::MAX,
+            y in i32::MIN..=i32::MAX
         ) {
             let request = super::ActionMoveRequest::builder()
                 .bearer_token(BearerToken("valid token".to_string()))
@@ -0,0 +1,77 @@
+use std::{marker::PhantomData, str::FromStr};
+
+use http::{header::AUTHORIZATION, uri::PathAndQuery, HeaderMap, HeaderValue, Method};
+use nutype::nutype;
+use typed_builder::TypedBuilder;
+
+use crate::{
+    helpers::{ACCEPT_JSON, CONTENT_TYPE_JSON},
+    rate_limits::ACTIONS_RATE_LIMIT,
+    schemas::{BearerToken, ResponseSchema, TaskRewardDataSchema},
+    EncodedRequest, ParseResponse, StatusErrors,
+};
+
+#[nutype(validate(regex = "^[a-zA-Z0-9_-]+$"))]
+struct Name(String);
+
+#[derive(TypedBuilder)]
+pub struct ActionTaskExchangeRequest {
+    bearer_token: BearerToken,
+    #[builder(setter(into))]
+    name: String,
+}
+/// SOURCE: <https://api.artifactsmmo.com/docs/#/operations/action_task_exchange_my__name__action_task_exchange_post>
+pub fn action_task_exchange(
+    ActionTaskExchangeRequest { bearer_token, name }: ActionTaskExchangeRequest,
+) -> Result<EncodedRequest<ActionTaskExchangeRequest>, crate::Error> {
+    let name = Name::try_new(name)
+        .map_err(|e| crate::Error::InvalidInput(e.to_string()))?
+        .into_inner();
+
+    Ok(EncodedRequest {
+        method: Method::POST,
+        path: PathAndQuery::from_str(&format!("/my/{name}/action/task/exchange"))?,
+        headers: HeaderMap::from_iter([
+            ACCEPT_JSON,
+            CONTENT_TYPE_JSON,
+            (
+                AUTHORIZATION,
+                HeaderValue::from_str(&format!("Bearer {}", bearer_token.0))?,
+            ),
+        ]),
+        content: Vec::new(),
+        rate_limit: ACTIONS_RATE_LIMIT,
+        marker: PhantomData,
+    })
+}
+
+impl<'de> ParseResponse<'de> for EncodedRequest<ActionTaskExchangeRequest> {
+    type Response = ResponseSchema<TaskRewardDataSchema>;
+    const STATUS_ERRORS: StatusErrors = &[
+        (478, crate::Error::MissingItem),
+        (486, crate::Error::ActionInProgress),
+        (497, crate::Error::InventoryFull),
+        (498, crate::Error::CharacterNotFound),
+        (499, crate::Error::CharacterInCooldown),
+        (598, crate::Error::ContentNotFoundOnMap),
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::schemas::BearerToken;
+    proptest! {
+        #[test]
+        fn action_task_exchange_should_not_panic_with_valid_input(
+            name in "[a-zA-Z0-9_-]+",
+        ) {
+            let request = super::ActionTaskExchangeRequest::builder()
+                .bearer_token(BearerToken("valid token".to_string()))
+                .name(name)
+                .build();
+            assert!(super::action_task_exchange(request).is_ok());
+        }
+    }
+}
@@ -12,13 +12,13 @@ use crate::{
         BearerToken, CharacterMovementDataSchema, EquipRequestSchema, ResponseSchema,
         SlotTypeSchema,
     },
-    EncodedRequest, ParseResponse,
+    EncodedRequest, ParseResponse, StatusErrors,
 };
 
 #[nutype(validate(regex = "^[a-zA-Z0-9_-]+$"))]
 struct Name(String);
 
-#[derive(TypedBuilder)]
+#[derive(Debug, Clone, TypedBuilder)]
 pub struct ActionUnequipItemRequest {
     bearer_token: BearerToken,
     #[builder(setter(into))]
@@ -54,6 +54,13 @@ pub fn action_unequip_item(
 
 impl<'de> ParseResponse<'de> for EncodedRequest<ActionUnequipItemRequest> {
     type Response = ResponseSchema<EquipRequestSchema>;
+    const STATUS_ERRORS: StatusErrors = &[
+        (486, crate::Error::ActionInProgress),
+        (491, crate::Error::SlotEmpty),
+        (497, crate::Error::InventoryFull),
+        (498, crate::Error::CharacterNotFound),
+        (499, crate::Error::CharacterInCooldown),
+    ];
 }
 
 #[cfg(test)]
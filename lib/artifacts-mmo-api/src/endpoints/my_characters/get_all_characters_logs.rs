@@ -0,0 +1,114 @@
+use std::{marker::PhantomData, str::FromStr};
+
+use http::{header::AUTHORIZATION, uri::PathAndQuery, HeaderMap, HeaderValue, Method};
+use nutype::nutype;
+use typed_builder::TypedBuilder;
+
+use crate::{
+    helpers::{Query, ACCEPT_JSON},
+    pagination::PaginatedRequest,
+    rate_limits::DATA_RATE_LIMIT,
+    schemas::{BearerToken, LogSchema, PaginatedResponseSchema},
+    EncodedRequest, ParseResponse, StatusErrors,
+};
+
+#[nutype(validate(greater_or_equal = 1))]
+struct Page(u32);
+#[nutype(validate(greater_or_equal = 1, less_or_equal = 100))]
+struct Size(u32);
+
+#[derive(Clone, TypedBuilder)]
+pub struct GetAllCharactersLogsRequest {
+    bearer_token: BearerToken,
+    #[builder(default = 1)]
+    page: u32,
+    #[builder(default = 50)]
+    size: u32,
+}
+/// SOURCE: <https://api.artifactsmmo.com/docs/#/operations/get_all_characters_logs_my_logs_get>
+pub fn get_all_characters_logs(
+    GetAllCharactersLogsRequest {
+        bearer_token,
+        page,
+        size,
+    }: GetAllCharactersLogsRequest,
+) -> Result<EncodedRequest<GetAllCharactersLogsRequest>, crate::Error> {
+    let page = Page::try_new(page)
+        .map_err(|e| crate::Error::InvalidInput(e.to_string()))?
+        .into_inner();
+    let size = Size::try_new(size)
+        .map_err(|e| crate::Error::InvalidInput(e.to_string()))?
+        .into_inner();
+
+    Ok(EncodedRequest {
+        method: Method::GET,
+        path: Query::new("/my/logs")
+            .param("page", page)
+            .param("size", size)
+            .build()?,
+        headers: HeaderMap::from_iter([
+            ACCEPT_JSON,
+            (
+                AUTHORIZATION,
+                HeaderValue::from_str(&format!("Bearer {}", bearer_token.0))?,
+            ),
+        ]),
+        content: Vec::new(),
+        rate_limit: DATA_RATE_LIMIT,
+        marker: PhantomData,
+    })
+}
+
+impl<'de> ParseResponse<'de> for EncodedRequest<GetAllCharactersLogsRequest> {
+    type Response = PaginatedResponseSchema<LogSchema>;
+    const STATUS_ERRORS: StatusErrors = &[
+        (404, crate::Error::NotFound),
+    ];
+}
+
+impl PaginatedRequest for GetAllCharactersLogsRequest {
+    fn page(&self) -> u32 {
+        self.page
+    }
+
+    fn set_page(&mut self, page: u32) {
+        self.page = page;
+    }
+
+    fn encode(self) -> Result<EncodedRequest<Self>, crate::Error> {
+        get_all_characters_logs(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::schemas::BearerToken;
+
+    proptest! {
+        #[test]
+        fn get_all_characters_logs_should_work_with_valid_input(
+            page in 1u32..=u32::MAX,
+            size in 1u32..=100,
+        ) {
+            let request = super::GetAllCharactersLogsRequest::builder()
+                .bearer_token(BearerToken("a valid token".to_string()))
+                .page(page)
+                .size(size)
+                .build();
+            assert!(super::get_all_characters_logs(request).is_ok());
+        }
+
+        #[test]
+        fn get_all_characters_logs_should_fail_with_invalid_size(
+            size in 101u32..=u32::MAX,
+        ) {
+            let request = super::GetAllCharactersLogsRequest::builder()
+                .bearer_token(BearerToken("a valid token".to_string()))
+                .size(size)
+                .build();
+            assert!(super::get_all_characters_logs(request).is_err());
+        }
+    }
+}
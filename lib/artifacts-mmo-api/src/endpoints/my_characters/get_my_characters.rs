@@ -0,0 +1,52 @@
+use std::marker::PhantomData;
+
+use http::{header::AUTHORIZATION, uri::PathAndQuery, HeaderMap, HeaderValue, Method};
+use typed_builder::TypedBuilder;
+
+use crate::{
+    helpers::ACCEPT_JSON,
+    rate_limits::DATA_RATE_LIMIT,
+    schemas::{BearerToken, CharacterSchema, ResponseSchema},
+    EncodedRequest, ParseResponse,
+};
+
+#[derive(TypedBuilder)]
+pub struct GetMyCharactersRequest {
+    bearer_token: BearerToken,
+}
+/// SOURCE: <https://api.artifactsmmo.com/docs/#/operations/get_my_characters_my_characters_get>
+pub fn get_my_characters(
+    GetMyCharactersRequest { bearer_token }: GetMyCharactersRequest,
+) -> Result<EncodedRequest<GetMyCharactersRequest>, crate::Error> {
+    Ok(EncodedRequest {
+        method: Method::GET,
+        path: PathAndQuery::from_static("/my/characters"),
+        headers: HeaderMap::from_iter([
+            ACCEPT_JSON,
+            (
+                AUTHORIZATION,
+                HeaderValue::from_str(&format!("Bearer {}", bearer_token.0))?,
+            ),
+        ]),
+        content: Vec::new(),
+        rate_limit: DATA_RATE_LIMIT,
+        marker: PhantomData,
+    })
+}
+
+impl<'de> ParseResponse<'de> for EncodedRequest<GetMyCharactersRequest> {
+    type Response = ResponseSchema<Vec<CharacterSchema>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schemas::BearerToken;
+
+    #[test]
+    fn get_my_characters_should_work_with_valid_input() {
+        let request = super::GetMyCharactersRequest::builder()
+            .bearer_token(BearerToken("a valid token".to_string()))
+            .build();
+        assert!(super::get_my_characters(request).is_ok());
+    }
+}
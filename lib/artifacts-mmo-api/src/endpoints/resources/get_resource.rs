@@ -8,14 +8,14 @@ use crate::{
     helpers::ACCEPT_JSON,
     rate_limits::DATA_RATE_LIMIT,
     schemas::{PaginatedResponseSchema, ResourceSchema, ResponseSchema, SkillSchema},
-    EncodedRequest, ParseResponse,
+    EncodedRequest, ParseResponse, StatusErrors,
 };
 
 #[nutype(validate(regex = "^[a-zA-Z0-9_-]+$"))]
 struct Code(String);
 
 #[derive(TypedBuilder)]
-struct GetResourceRequest {
+pub struct GetResourceRequest {
     #[builder(setter(into))]
     code: String,
 }
@@ -40,6 +40,9 @@ pub fn get_resource(
 
 impl<'de> ParseResponse<'de> for EncodedRequest<GetResourceRequest> {
     type Response = ResponseSchema<ResourceSchema>;
+    const STATUS_ERRORS: StatusErrors = &[
+        (404, crate::Error::NotFound),
+    ];
 }
 
 #[cfg(test)]
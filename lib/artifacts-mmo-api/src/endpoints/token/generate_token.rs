@@ -14,7 +14,7 @@ use crate::{
 };
 
 #[derive(TypedBuilder)]
-struct GenerateTokenRequest {
+pub struct GenerateTokenRequest {
     #[builder(setter(into))]
     username: String,
     #[builder(setter(into))]
@@ -0,0 +1,223 @@
+//! Local cache of the static game data, it only changes on wipes so it can be saved between runs
+
+use std::{
+    collections::HashMap,
+    io::{Read, Write},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::schemas::{ItemSchema, MapSchema, MonsterSchema, ResourceSchema, StatusSchema};
+
+/// Bumped when the layout of the snapshot changes
+pub const SNAPSHOT_FORMAT_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GameData {
+    #[serde(flatten)]
+    header: SnapshotHeader,
+    items: HashMap<String, ItemSchema>,
+    monsters: HashMap<String, MonsterSchema>,
+    resources: HashMap<String, ResourceSchema>,
+    maps: Vec<MapSchema>,
+}
+
+/// Fields of a snapshot that don't depend on its layout
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct SnapshotHeader {
+    format_version: u32,
+    server_version: String,
+    last_wipe: String,
+}
+
+impl SnapshotHeader {
+    fn is_valid_for(&self, status: &StatusSchema) -> bool {
+        self.format_version == SNAPSHOT_FORMAT_VERSION
+            && self.server_version == status.version
+            && self.last_wipe == status.last_wipe
+    }
+}
+
+impl GameData {
+    /// Empty store for the server described by `status`
+    pub fn new(status: &StatusSchema) -> Self {
+        Self {
+            header: SnapshotHeader {
+                format_version: SNAPSHOT_FORMAT_VERSION,
+                server_version: status.version.clone(),
+                last_wipe: status.last_wipe.clone(),
+            },
+            ..Default::default()
+        }
+    }
+
+    /// Whether the data still describes the server answering with `status`
+    pub fn is_valid_for(&self, status: &StatusSchema) -> bool {
+        self.header.is_valid_for(status)
+    }
+
+    pub fn extend_items(&mut self, items: impl IntoIterator<Item = ItemSchema>) {
+        self.items
+            .extend(items.into_iter().map(|item| (item.code.clone(), item)));
+    }
+
+    pub fn extend_monsters(&mut self, monsters: impl IntoIterator<Item = MonsterSchema>) {
+        self.monsters.extend(
+            monsters
+                .into_iter()
+                .map(|monster| (monster.code.clone(), monster)),
+        );
+    }
+
+    pub fn extend_resources(&mut self, resources: impl IntoIterator<Item = ResourceSchema>) {
+        self.resources.extend(
+            resources
+                .into_iter()
+                .map(|resource| (resource.code.clone(), resource)),
+        );
+    }
+
+    pub fn extend_maps(&mut self, maps: impl IntoIterator<Item = MapSchema>) {
+        self.maps.extend(maps);
+    }
+
+    pub fn item(&self, code: &str) -> Option<&ItemSchema> {
+        self.items.get(code)
+    }
+
+    pub fn monster(&self, code: &str) -> Option<&MonsterSchema> {
+        self.monsters.get(code)
+    }
+
+    pub fn resource(&self, code: &str) -> Option<&ResourceSchema> {
+        self.resources.get(code)
+    }
+
+    pub fn items(&self) -> impl Iterator<Item = &ItemSchema> {
+        self.items.values()
+    }
+
+    pub fn monsters(&self) -> impl Iterator<Item = &MonsterSchema> {
+        self.monsters.values()
+    }
+
+    pub fn resources(&self) -> impl Iterator<Item = &ResourceSchema> {
+        self.resources.values()
+    }
+
+    pub fn maps(&self) -> &[MapSchema] {
+        &self.maps
+    }
+
+    pub fn save(&self, writer: impl Write) -> Result<(), crate::Error> {
+        Ok(serde_json::to_writer(writer, self)?)
+    }
+
+    /// Load a snapshot, `None` if it was taken on another server version, before the last wipe
+    /// or with another layout
+    pub fn load(
+        mut reader: impl Read,
+        status: &StatusSchema,
+    ) -> Result<Option<Self>, crate::Error> {
+        let mut snapshot = Vec::new();
+        reader
+            .read_to_end(&mut snapshot)
+            .map_err(serde_json::Error::io)?;
+
+        // The header is read first so that a snapshot in an older layout is only invalidated
+        let header: SnapshotHeader = serde_json::from_slice(&snapshot)?;
+        if !header.is_valid_for(status) {
+            return Ok(None);
+        }
+        Ok(Some(serde_json::from_slice(&snapshot)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GameData;
+    use crate::schemas::{ItemSchema, ItemTypeSchema, StatusSchema};
+
+    fn status(version: &str, last_wipe: &str) -> StatusSchema {
+        StatusSchema {
+            status: "online".to_string(),
+            version: version.to_string(),
+            characters_online: 0,
+            announcements: Vec::new(),
+            last_wipe: last_wipe.to_string(),
+            next_wipe: String::new(),
+        }
+    }
+
+    fn item(code: &str) -> ItemSchema {
+        ItemSchema {
+            name: code.to_string(),
+            code: code.to_string(),
+            level: 1,
+            r#type: ItemTypeSchema::Resource,
+            subtype: String::new(),
+            description: String::new(),
+            effects: Vec::new(),
+            craft: None,
+        }
+    }
+
+    #[test]
+    fn game_data_should_lookup_by_code() {
+        let mut game_data = GameData::new(&status("1.3", "2024-08-01"));
+        game_data.extend_items([item("copper_ore"), item("ash_wood")]);
+
+        assert_eq!(game_data.item("copper_ore").unwrap().code, "copper_ore");
+        assert!(game_data.item("iron_ore").is_none());
+        assert_eq!(game_data.items().count(), 2);
+    }
+
+    #[test]
+    fn game_data_should_survive_a_snapshot() {
+        let status = status("1.3", "2024-08-01");
+        let mut game_data = GameData::new(&status);
+        game_data.extend_items([item("copper_ore")]);
+
+        let mut snapshot = Vec::new();
+        game_data.save(&mut snapshot).unwrap();
+        let loaded = GameData::load(snapshot.as_slice(), &status)
+            .unwrap()
+            .unwrap();
+
+        assert!(loaded.item("copper_ore").is_some());
+    }
+
+    #[test]
+    fn game_data_should_be_invalidated_by_a_new_version_or_wipe() {
+        let mut snapshot = Vec::new();
+        GameData::new(&status("1.3", "2024-08-01"))
+            .save(&mut snapshot)
+            .unwrap();
+
+        assert!(
+            GameData::load(snapshot.as_slice(), &status("1.4", "2024-08-01"))
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            GameData::load(snapshot.as_slice(), &status("1.3", "2024-09-01"))
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn game_data_should_invalidate_older_layouts() {
+        let status = status("1.3", "2024-08-01");
+        let snapshot = serde_json::json!({
+            "format_version": 0,
+            "server_version": "1.3",
+            "last_wipe": "2024-08-01",
+            "items": [{"code": "copper_ore"}],
+        });
+
+        assert!(GameData::load(snapshot.to_string().as_bytes(), &status)
+            .unwrap()
+            .is_none());
+    }
+}
@@ -0,0 +1,476 @@
+//! Gear optimizer choosing the equipment to fight a monster with
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::{
+    combat::{self, SimulatedFight},
+    endpoints::{ActionEquipItemRequest, ActionUnequipItemRequest},
+    game_data::GameData,
+    schemas::{
+        BearerToken, CharacterSchema, ItemSchema, ItemTypeSchema, MonsterSchema, ResultSchema,
+        SimpleItemSchema, SlotTypeSchema,
+    },
+};
+
+/// Slots holding gear, consumables are not part of a loadout
+pub const GEAR_SLOTS: [SlotTypeSchema; 12] = [
+    SlotTypeSchema::Weapon,
+    SlotTypeSchema::Shield,
+    SlotTypeSchema::Helmet,
+    SlotTypeSchema::BodyArmor,
+    SlotTypeSchema::LegArmor,
+    SlotTypeSchema::Boots,
+    SlotTypeSchema::Ring1,
+    SlotTypeSchema::Ring2,
+    SlotTypeSchema::Amulet,
+    SlotTypeSchema::Artifact1,
+    SlotTypeSchema::Artifact2,
+    SlotTypeSchema::Artifact3,
+];
+
+/// Passes over every slot before giving up on finding a better loadout
+const MAX_PASSES: usize = 4;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GearChange {
+    Unequip { slot: SlotTypeSchema },
+    Equip { slot: SlotTypeSchema, code: String },
+}
+
+#[derive(Debug, Clone)]
+pub enum GearRequest {
+    Equip(ActionEquipItemRequest),
+    Unequip(ActionUnequipItemRequest),
+}
+
+#[derive(Debug, Clone)]
+pub struct Loadout {
+    /// Item per slot of `GEAR_SLOTS`, in the same order
+    pub slots: Vec<Option<String>>,
+    /// Expected fight with this loadout
+    pub fight: SimulatedFight,
+    /// Items to take out of the bank before equipping
+    pub withdraw: BTreeMap<String, u32>,
+    /// Unequips first so the slots are empty when equipping
+    pub changes: Vec<GearChange>,
+}
+
+impl Loadout {
+    pub fn requests(&self, bearer_token: &BearerToken, name: &str) -> Vec<GearRequest> {
+        self.changes
+            .iter()
+            .map(|change| match change {
+                GearChange::Unequip { slot } => GearRequest::Unequip(
+                    ActionUnequipItemRequest::builder()
+                        .bearer_token(bearer_token.clone())
+                        .name(name)
+                        .slot(slot.clone())
+                        .build(),
+                ),
+                GearChange::Equip { slot, code } => GearRequest::Equip(
+                    ActionEquipItemRequest::builder()
+                        .bearer_token(bearer_token.clone())
+                        .name(name)
+                        .code(code)
+                        .slot(slot.clone())
+                        .build(),
+                ),
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct GearOptimizer<'a> {
+    items: HashMap<&'a str, &'a ItemSchema>,
+}
+
+impl<'a> GearOptimizer<'a> {
+    pub fn new(items: impl IntoIterator<Item = &'a ItemSchema>) -> Self {
+        Self {
+            items: items
+                .into_iter()
+                .map(|item| (item.code.as_str(), item))
+                .collect(),
+        }
+    }
+
+    /// Improves one slot at a time until no single change wins faster, so the result is a local
+    /// optimum rather than the best of every combination
+    pub fn optimize(
+        &self,
+        character: &CharacterSchema,
+        bank: &[SimpleItemSchema],
+        monster: &MonsterSchema,
+    ) -> Loadout {
+        let current: Vec<Option<String>> = GEAR_SLOTS
+            .iter()
+            .map(|slot| character.slot(slot).map(str::to_string))
+            .collect();
+        let mut base = character.clone();
+        for code in current.iter().flatten() {
+            self.apply(&mut base, code, false);
+        }
+
+        let mut owned: HashMap<&str, u32> = HashMap::new();
+        for (code, quantity) in character
+            .inventory
+            .iter()
+            .map(|slot| (slot.code.as_str(), slot.quantity))
+            .chain(bank.iter().map(|item| (item.code.as_str(), item.quantity)))
+            .chain(current.iter().flatten().map(|code| (code.as_str(), 1)))
+        {
+            if !code.is_empty() {
+                let count = owned.entry(code).or_default();
+                *count = count.saturating_add(quantity);
+            }
+        }
+
+        let candidates: Vec<Vec<&str>> = GEAR_SLOTS
+            .iter()
+            .map(|slot| {
+                let mut codes: Vec<&str> = owned
+                    .keys()
+                    .copied()
+                    .filter(|code| {
+                        self.items.get(code).is_some_and(|item| {
+                            item.level <= character.level && fits(&item.r#type, slot)
+                        })
+                    })
+                    .collect();
+                codes.sort_unstable();
+                codes
+            })
+            .collect();
+
+        let mut slots = current.clone();
+        let mut best = self.simulate(&base, &slots, monster);
+        for _ in 0..MAX_PASSES {
+            let mut improved = false;
+            for (index, codes) in candidates.iter().enumerate() {
+                for code in codes
+                    .iter()
+                    .map(|code| Some(code.to_string()))
+                    .chain([None])
+                {
+                    if slots[index] == code {
+                        continue;
+                    }
+                    let mut candidate = slots.clone();
+                    candidate[index] = code;
+                    if !is_available(&candidate, &owned) {
+                        continue;
+                    }
+                    let fight = self.simulate(&base, &candidate, monster);
+                    if score(&fight) > score(&best) {
+                        slots = candidate;
+                        best = fight;
+                        improved = true;
+                    }
+                }
+            }
+            if !improved {
+                break;
+            }
+        }
+
+        let (withdraw, changes) = changes(character, &current, &slots);
+        Loadout {
+            slots,
+            fight: best,
+            withdraw,
+            changes,
+        }
+    }
+
+    fn simulate(
+        &self,
+        base: &CharacterSchema,
+        slots: &[Option<String>],
+        monster: &MonsterSchema,
+    ) -> SimulatedFight {
+        let mut character = base.clone();
+        for code in slots.iter().flatten() {
+            self.apply(&mut character, code, true);
+        }
+        combat::simulate(&character, monster)
+    }
+
+    fn apply(&self, character: &mut CharacterSchema, code: &str, equip: bool) {
+        let Some(item) = self.items.get(code) else {
+            return;
+        };
+        for effect in &item.effects {
+            let Some(stat) = character.stat_mut(&effect.name) else {
+                continue;
+            };
+            *stat = if equip {
+                stat.saturating_add(effect.value)
+            } else {
+                stat.saturating_sub(effect.value)
+            };
+        }
+    }
+}
+
+impl<'a> From<&'a GameData> for GearOptimizer<'a> {
+    fn from(game_data: &'a GameData) -> Self {
+        Self::new(game_data.items())
+    }
+}
+
+/// Wins first, then the fastest win, or the most damage dealt when the fight is lost
+fn score(fight: &SimulatedFight) -> (bool, u32, u32) {
+    match fight.result {
+        ResultSchema::Win => (true, u32::MAX - fight.turns, fight.character_hp),
+        ResultSchema::Loss => (false, fight.character_damage.total, fight.character_hp),
+    }
+}
+
+/// Whether an item of type `r#type` can be equipped in `slot`
+pub fn fits(r#type: &ItemTypeSchema, slot: &SlotTypeSchema) -> bool {
+    use SlotTypeSchema as S;
+    matches!(
+        (r#type, slot),
+        (ItemTypeSchema::Weapon, S::Weapon)
+            | (ItemTypeSchema::Shield, S::Shield)
+            | (ItemTypeSchema::Helmet, S::Helmet)
+            | (ItemTypeSchema::BodyArmor, S::BodyArmor)
+            | (ItemTypeSchema::LegArmor, S::LegArmor)
+            | (ItemTypeSchema::Boots, S::Boots)
+            | (ItemTypeSchema::Ring, S::Ring1 | S::Ring2)
+            | (ItemTypeSchema::Amulet, S::Amulet)
+            | (
+                ItemTypeSchema::Artifact,
+                S::Artifact1 | S::Artifact2 | S::Artifact3
+            )
+            | (ItemTypeSchema::Consumable, S::Consumable1 | S::Consumable2)
+    )
+}
+
+/// The same ring can be worn twice when two are owned, an artifact can only be worn once
+fn is_available(slots: &[Option<String>], owned: &HashMap<&str, u32>) -> bool {
+    let mut used: HashMap<&str, u32> = HashMap::new();
+    for (slot, code) in GEAR_SLOTS.iter().zip(slots) {
+        let Some(code) = code else {
+            continue;
+        };
+        let count = used.entry(code).or_default();
+        *count = count.saturating_add(1);
+        let is_artifact = matches!(
+            slot,
+            SlotTypeSchema::Artifact1 | SlotTypeSchema::Artifact2 | SlotTypeSchema::Artifact3
+        );
+        if (is_artifact && *count > 1) || *count > owned.get(code.as_str()).copied().unwrap_or(0) {
+            return false;
+        }
+    }
+    true
+}
+
+fn changes(
+    character: &CharacterSchema,
+    current: &[Option<String>],
+    target: &[Option<String>],
+) -> (BTreeMap<String, u32>, Vec<GearChange>) {
+    let mut in_inventory: HashMap<&str, u32> = HashMap::new();
+    for slot in &character.inventory {
+        let count = in_inventory.entry(slot.code.as_str()).or_default();
+        *count = count.saturating_add(slot.quantity);
+    }
+
+    let mut unequips = Vec::new();
+    let mut equips = Vec::new();
+    for ((slot, from), to) in GEAR_SLOTS.iter().zip(current).zip(target) {
+        if from == to {
+            continue;
+        }
+        if let Some(code) = from {
+            let count = in_inventory.entry(code).or_default();
+            *count = count.saturating_add(1);
+            unequips.push(GearChange::Unequip { slot: slot.clone() });
+        }
+        if let Some(code) = to {
+            equips.push(GearChange::Equip {
+                slot: slot.clone(),
+                code: code.clone(),
+            });
+        }
+    }
+
+    let mut withdraw: BTreeMap<String, u32> = BTreeMap::new();
+    for change in &equips {
+        let GearChange::Equip { code, .. } = change else {
+            continue;
+        };
+        match in_inventory.get_mut(code.as_str()) {
+            Some(count) if *count > 0 => *count -= 1,
+            _ => {
+                let count = withdraw.entry(code.clone()).or_default();
+                *count = count.saturating_add(1);
+            }
+        }
+    }
+
+    unequips.extend(equips);
+    (withdraw, unequips)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{GearChange, GearOptimizer, GEAR_SLOTS};
+    use crate::{
+        schemas::{
+            InventorySlotSchema, ItemEffectSchema, ItemSchema, ItemTypeSchema, ResultSchema,
+            SimpleItemSchema, SlotTypeSchema,
+        },
+        test_support::{character, monster},
+    };
+
+    fn item(code: &str, r#type: ItemTypeSchema, level: u32, effects: &[(&str, u32)]) -> ItemSchema {
+        ItemSchema {
+            name: code.to_string(),
+            code: code.to_string(),
+            level,
+            r#type,
+            subtype: String::new(),
+            description: String::new(),
+            effects: effects
+                .iter()
+                .map(|(name, value)| ItemEffectSchema {
+                    name: name.to_string(),
+                    value: *value,
+                })
+                .collect(),
+            craft: None,
+        }
+    }
+
+    fn items() -> Vec<ItemSchema> {
+        vec![
+            item(
+                "wooden_stick",
+                ItemTypeSchema::Weapon,
+                1,
+                &[("attack_earth", 4)],
+            ),
+            item(
+                "fire_staff",
+                ItemTypeSchema::Weapon,
+                5,
+                &[("attack_fire", 12)],
+            ),
+            item(
+                "water_bow",
+                ItemTypeSchema::Weapon,
+                1,
+                &[("attack_water", 8)],
+            ),
+            item("copper_ring", ItemTypeSchema::Ring, 1, &[("dmg_water", 10)]),
+            item("life_amulet", ItemTypeSchema::Amulet, 1, &[("hp", 20)]),
+            item(
+                "water_charm",
+                ItemTypeSchema::Artifact,
+                1,
+                &[("dmg_water", 20)],
+            ),
+        ]
+    }
+
+    fn slot(slots: &[Option<String>], slot: SlotTypeSchema) -> Option<&str> {
+        let index = GEAR_SLOTS.iter().position(|s| *s == slot).unwrap();
+        slots[index].as_deref()
+    }
+
+    #[test]
+    fn optimize_should_pick_best_items_within_level() {
+        let items = items();
+        let mut character = character(
+            100,
+            json!({ "level": 2, "attack_earth": 4, "weapon_slot": "wooden_stick" }),
+        );
+        character.inventory = vec![
+            InventorySlotSchema {
+                slot: 1,
+                code: "fire_staff".to_string(),
+                quantity: 1,
+            },
+            InventorySlotSchema {
+                slot: 2,
+                code: "copper_ring".to_string(),
+                quantity: 2,
+            },
+        ];
+        let bank = [
+            SimpleItemSchema {
+                code: "water_bow".to_string(),
+                quantity: 1,
+            },
+            SimpleItemSchema {
+                code: "water_charm".to_string(),
+                quantity: 3,
+            },
+        ];
+        let monster = monster(88, json!({ "attack_fire": 5, "res_earth": 50 }));
+
+        let loadout = GearOptimizer::new(&items).optimize(&character, &bank, &monster);
+
+        assert_eq!(loadout.fight.result, ResultSchema::Win);
+        // The fire staff is too high level
+        assert_eq!(
+            slot(&loadout.slots, SlotTypeSchema::Weapon),
+            Some("water_bow")
+        );
+        assert_eq!(
+            slot(&loadout.slots, SlotTypeSchema::Ring1),
+            Some("copper_ring")
+        );
+        assert_eq!(
+            slot(&loadout.slots, SlotTypeSchema::Ring2),
+            Some("copper_ring")
+        );
+        let artifacts = [
+            SlotTypeSchema::Artifact1,
+            SlotTypeSchema::Artifact2,
+            SlotTypeSchema::Artifact3,
+        ]
+        .map(|artifact| slot(&loadout.slots, artifact));
+        assert_eq!(
+            artifacts.iter().filter(|code| code.is_some()).count(),
+            1,
+            "an artifact can only be equipped once"
+        );
+        assert_eq!(loadout.withdraw.get("water_bow"), Some(&1));
+        assert_eq!(loadout.withdraw.get("water_charm"), Some(&1));
+        assert_eq!(
+            loadout.changes.first(),
+            Some(&GearChange::Unequip {
+                slot: SlotTypeSchema::Weapon
+            })
+        );
+        assert_eq!(
+            loadout
+                .requests(&crate::schemas::BearerToken(String::new()), "tester")
+                .len(),
+            loadout.changes.len()
+        );
+    }
+
+    #[test]
+    fn optimize_should_keep_loadout_when_nothing_is_better() {
+        let items = items();
+        let character = character(40, json!({ "attack_water": 8, "weapon_slot": "water_bow" }));
+        let monster = monster(10, json!({}));
+
+        let loadout = GearOptimizer::new(&items).optimize(&character, &[], &monster);
+
+        assert_eq!(
+            slot(&loadout.slots, SlotTypeSchema::Weapon),
+            Some("water_bow")
+        );
+        assert!(loadout.changes.is_empty());
+        assert!(loadout.withdraw.is_empty());
+    }
+}
@@ -1,11 +1,176 @@
 #![allow(clippy::declare_interior_mutable_const)]
+use std::{fmt::Display, str::FromStr};
+
 use http::{
     header::{ACCEPT, CONTENT_TYPE},
+    uri::PathAndQuery,
     HeaderName, HeaderValue,
 };
+use serde::{
+    de::{self, DeserializeOwned},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
 
 pub const ACCEPT_JSON: (HeaderName, HeaderValue) =
     (ACCEPT, HeaderValue::from_static("application/json"));
 
 pub const CONTENT_TYPE_JSON: (HeaderName, HeaderValue) =
     (CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+/// Path with a query string, keys and values are percent-encoded
+#[derive(Debug, Clone)]
+pub struct Query {
+    path: String,
+    params: Vec<(&'static str, String)>,
+}
+
+impl Query {
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            params: Vec::new(),
+        }
+    }
+
+    pub fn param(mut self, key: &'static str, value: impl Display) -> Self {
+        self.params.push((key, value.to_string()));
+        self
+    }
+
+    /// Filters left to `None` are not sent
+    pub fn optional(self, key: &'static str, value: Option<impl Display>) -> Self {
+        match value {
+            Some(value) => self.param(key, value),
+            None => self,
+        }
+    }
+
+    pub fn build(self) -> Result<PathAndQuery, crate::Error> {
+        let query = self
+            .params
+            .iter()
+            .map(|(key, value)| format!("{}={}", percent_encode(key), percent_encode(value)))
+            .collect::<Vec<_>>()
+            .join("&");
+        let path = if query.is_empty() {
+            self.path
+        } else {
+            format!("{}?{query}", self.path)
+        };
+        Ok(PathAndQuery::from_str(&path)?)
+    }
+}
+
+/// Encode everything but the unreserved characters of RFC 3986
+pub fn percent_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
+/// Deserialize an empty string as `None`, anything else through `T`'s own implementation
+pub fn empty_string_as_none<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned,
+{
+    let value = serde_json::Value::deserialize(deserializer)?;
+    if value.as_str().is_some_and(str::is_empty) {
+        return Ok(None);
+    }
+    T::deserialize(value).map(Some).map_err(de::Error::custom)
+}
+
+/// Counterpart of `empty_string_as_none`
+pub fn none_as_empty_string<S, T>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    match value {
+        Some(value) => value.serialize(serializer),
+        None => serializer.serialize_str(""),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use serde::Deserialize;
+
+    use super::Query;
+
+    use crate::{schemas::TaskTypeSchema, test_support::query_params};
+
+    #[derive(Deserialize)]
+    struct Wrapper {
+        #[serde(deserialize_with = "super::empty_string_as_none")]
+        task_type: Option<TaskTypeSchema>,
+    }
+
+    #[test]
+    fn empty_string_should_be_none() {
+        let wrapper: Wrapper = serde_json::from_str(r#"{"task_type":""}"#).unwrap();
+        assert!(wrapper.task_type.is_none());
+    }
+
+    #[test]
+    fn non_empty_string_should_be_parsed() {
+        let wrapper: Wrapper = serde_json::from_str(r#"{"task_type":"monsters"}"#).unwrap();
+        assert!(matches!(wrapper.task_type, Some(TaskTypeSchema::Monsters)));
+    }
+
+    #[test]
+    fn query_without_params_should_have_no_question_mark() {
+        let path = Query::new("/items/")
+            .optional("name", None::<&str>)
+            .build()
+            .unwrap();
+        assert_eq!(path.as_str(), "/items/");
+    }
+
+    #[test]
+    fn query_should_encode_reserved_characters() {
+        let path = Query::new("/items/")
+            .param("page", 1)
+            .param("name", "copper & iron=ore")
+            .build()
+            .unwrap();
+        assert_eq!(
+            path.as_str(),
+            "/items/?page=1&name=copper%20%26%20iron%3Dore"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn query_should_parse_back_into_params(values in prop::collection::vec(".*", 0..5)) {
+            let keys = ["page", "size", "name", "type", "drop"];
+            let query = keys
+                .iter()
+                .zip(&values)
+                .fold(Query::new("/items/"), |query, (key, value)| query.param(key, value));
+            let path = query.build().unwrap();
+
+            prop_assert_eq!(path.path(), "/items/");
+            prop_assert!(!path.as_str().ends_with('&'));
+            if values.is_empty() {
+                prop_assert_eq!(path.query(), None);
+            } else {
+                let expected: Vec<(String, String)> = keys
+                    .iter()
+                    .zip(&values)
+                    .map(|(key, value)| (key.to_string(), value.clone()))
+                    .collect();
+                prop_assert_eq!(query_params(&path), expected);
+            }
+        }
+    }
+}
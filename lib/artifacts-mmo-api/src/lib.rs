@@ -1,15 +1,28 @@
+pub mod cassette;
+pub mod character;
+pub mod combat;
+pub mod cooldowns;
+pub mod crafting;
 pub mod endpoints;
+pub mod game_data;
+pub mod gear;
 mod helpers;
+#[cfg(test)]
+mod openapi;
+pub mod pagination;
 pub mod rate_limits;
 pub mod schemas;
+#[cfg(test)]
+mod test_support;
+pub mod world;
 
 use std::marker::PhantomData;
 
-use http::{uri::PathAndQuery, HeaderMap, Method, Request};
+use http::{uri::PathAndQuery, HeaderMap, Method, Request, StatusCode};
 use serde::Deserialize;
 use thiserror::Error;
 
-use self::rate_limits::RateLimit;
+use self::{rate_limits::RateLimit, schemas::ErrorResponseSchema};
 
 pub const API_VERSION: &str = "v1.3";
 pub const API_BASE_URL: &str = "https://api.artifactsmmo.com";
@@ -24,9 +37,85 @@ pub enum Error {
     InvalidInput(String),
     #[error("Failed to parse JSON: {0}")]
     ParseJson(#[from] serde_json::Error),
+    #[error("Grand Exchange price of {code} changed: requested {requested}, current {current}")]
+    GEPriceMismatch {
+        code: String,
+        requested: u32,
+        current: u32,
+    },
+    #[error("Crafting recipes form a cycle: {}", .0.join(" -> "))]
+    CraftCycle(Vec<String>),
+    #[error("Crafting {0} needs more items than a quantity can hold")]
+    CraftOverflow(String),
+    #[error("No recorded response for {method} {path}")]
+    NotRecorded { method: String, path: String },
+    // Errors returned by the API, the status codes are documented per endpoint
+    // SOURCE: <https://docs.artifactsmmo.com/api_guide/response_codes>
+    #[error("Not found: {0}")]
+    NotFound(String),
+    #[error("Invalid payload: {0}")]
+    InvalidPayload(String),
+    #[error("Username already used: {0}")]
+    UsernameAlreadyUsed(String),
+    #[error("Email already used: {0}")]
+    EmailAlreadyUsed(String),
+    #[error("Insufficient gold in bank: {0}")]
+    BankInsufficientGold(String),
+    #[error("Transaction already in progress: {0}")]
+    TransactionInProgress(String),
+    #[error("Bank is full: {0}")]
+    BankFull(String),
+    #[error("Item cannot be recycled: {0}")]
+    ItemCannotBeRecycled(String),
+    #[error("Missing item or insufficient quantity: {0}")]
+    MissingItem(String),
+    #[error("Grand Exchange has no stock for this item: {0}")]
+    GENoStock(String),
+    // Returned by the server, see `Error::GEPriceMismatch` for the client-side guard
+    #[error("Grand Exchange has no item at this price: {0}")]
+    GEPriceChanged(String),
+    #[error("Item already equipped: {0}")]
+    ItemAlreadyEquipped(String),
+    #[error("An action is already in progress: {0}")]
+    ActionInProgress(String),
+    #[error("Character has no task: {0}")]
+    NoTask(String),
+    #[error("Task not completed: {0}")]
+    TaskNotCompleted(String),
+    #[error("Character already has a task: {0}")]
+    TaskAlreadyAssigned(String),
+    #[error("Character already at destination: {0}")]
+    AlreadyAtDestination(String),
+    #[error("Slot is not empty: {0}")]
+    SlotNotEmpty(String),
+    #[error("Slot is empty: {0}")]
+    SlotEmpty(String),
+    #[error("Insufficient gold: {0}")]
+    InsufficientGold(String),
+    #[error("Skill level too low: {0}")]
+    SkillLevelTooLow(String),
+    #[error("Name already used: {0}")]
+    NameAlreadyUsed(String),
+    #[error("Maximum characters reached: {0}")]
+    MaxCharactersReached(String),
+    #[error("Character level too low: {0}")]
+    LevelTooLow(String),
+    #[error("Inventory is full: {0}")]
+    InventoryFull(String),
+    #[error("Character not found: {0}")]
+    CharacterNotFound(String),
+    #[error("Character in cooldown: {0}")]
+    CharacterInCooldown(String),
+    #[error("Content not found on this map: {0}")]
+    ContentNotFoundOnMap(String),
+    #[error("Unexpected status {status}: {message}")]
+    UnexpectedStatus { status: StatusCode, message: String },
 }
 
-#[derive(Debug, Clone)]
+/// Map a documented status code to the error constructor taking the message sent by the API
+pub type StatusErrors = &'static [(u16, fn(String) -> Error)];
+
+#[derive(Debug)]
 pub struct EncodedRequest<T> {
     pub method: Method,
     pub path: PathAndQuery,
@@ -38,12 +127,51 @@ pub struct EncodedRequest<T> {
     marker: PhantomData<T>,
 }
 
+// Derived `Clone` would require `T: Clone` while `T` is only a marker
+impl<T> Clone for EncodedRequest<T> {
+    fn clone(&self) -> Self {
+        Self {
+            method: self.method.clone(),
+            path: self.path.clone(),
+            headers: self.headers.clone(),
+            content: self.content.clone(),
+            rate_limit: self.rate_limit.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
 pub trait ParseResponse<'de> {
     type Response: Deserialize<'de>;
+    /// Error status codes documented for the endpoint, 422 is handled for every endpoint
+    const STATUS_ERRORS: StatusErrors = &[];
 
     fn parse_response(response: &'de [u8]) -> Result<Self::Response, Error> {
         Ok(serde_json::from_slice(response)?)
     }
+
+    fn parse_http_response(
+        status: StatusCode,
+        response: &'de [u8],
+    ) -> Result<Self::Response, Error> {
+        if status.is_success() {
+            return Self::parse_response(response);
+        }
+
+        let message = serde_json::from_slice::<ErrorResponseSchema>(response)
+            .map(|response| response.error.message)
+            .unwrap_or_else(|_| String::from_utf8_lossy(response).into_owned());
+        let error = Self::STATUS_ERRORS
+            .iter()
+            .find(|(code, _)| *code == status.as_u16())
+            .map(|(_, error)| error);
+
+        Err(match error {
+            Some(error) => error(message),
+            None if status == StatusCode::UNPROCESSABLE_ENTITY => Error::InvalidPayload(message),
+            None => Error::UnexpectedStatus { status, message },
+        })
+    }
 }
 
 impl<T> TryFrom<EncodedRequest<T>> for Request<Vec<u8>> {
@@ -60,3 +188,75 @@ impl<T> TryFrom<EncodedRequest<T>> for Request<Vec<u8>> {
         request.body(value.content)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+
+    use crate::{
+        endpoints::{ActionEquipItemRequest, ActionUnequipItemRequest, GetMyCharactersRequest},
+        EncodedRequest, Error, ParseResponse,
+    };
+
+    const COOLDOWN_BODY: &[u8] =
+        br#"{"error":{"code":499,"message":"Character in cooldown: 12 seconds left."}}"#;
+
+    #[test]
+    fn parse_http_response_should_map_documented_status() {
+        let result = <EncodedRequest<ActionEquipItemRequest> as ParseResponse>::parse_http_response(
+            StatusCode::from_u16(499).unwrap(),
+            COOLDOWN_BODY,
+        );
+        assert!(
+            matches!(result, Err(Error::CharacterInCooldown(message)) if message == "Character in cooldown: 12 seconds left.")
+        );
+    }
+
+    #[test]
+    fn parse_http_response_should_map_status_per_endpoint() {
+        let body = br#"{"error":{"code":491,"message":"Slot"}}"#;
+        let status = StatusCode::from_u16(491).unwrap();
+        assert!(matches!(
+            <EncodedRequest<ActionEquipItemRequest> as ParseResponse>::parse_http_response(
+                status, body
+            ),
+            Err(Error::SlotNotEmpty(_))
+        ));
+        assert!(matches!(
+            <EncodedRequest<ActionUnequipItemRequest> as ParseResponse>::parse_http_response(
+                status, body
+            ),
+            Err(Error::SlotEmpty(_))
+        ));
+    }
+
+    #[test]
+    fn parse_http_response_should_handle_validation_errors() {
+        let result = <EncodedRequest<GetMyCharactersRequest> as ParseResponse>::parse_http_response(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            b"not json",
+        );
+        assert!(matches!(result, Err(Error::InvalidPayload(message)) if message == "not json"));
+    }
+
+    #[test]
+    fn parse_http_response_should_keep_undocumented_status() {
+        let result = <EncodedRequest<GetMyCharactersRequest> as ParseResponse>::parse_http_response(
+            StatusCode::from_u16(499).unwrap(),
+            COOLDOWN_BODY,
+        );
+        assert!(matches!(
+            result,
+            Err(Error::UnexpectedStatus { status, .. }) if status.as_u16() == 499
+        ));
+    }
+
+    #[test]
+    fn parse_http_response_should_parse_success() {
+        let result = <EncodedRequest<GetMyCharactersRequest> as ParseResponse>::parse_http_response(
+            StatusCode::OK,
+            br#"{"data":[]}"#,
+        );
+        assert!(matches!(result, Ok(response) if response.data.is_empty()));
+    }
+}
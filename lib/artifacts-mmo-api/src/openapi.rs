@@ -0,0 +1,544 @@
+//! Drift check of the hand-written endpoints and schemas against the Artifacts OpenAPI spec
+//!
+//! The spec is vendored as `openapi/artifacts-v1.3.json`, the `openapi.json` served by the API
+//! for [`crate::API_VERSION`] (`ARTIFACTS_OPENAPI` points to another copy). The check fails when
+//! the file is missing and stays ignored until it is committed, run it with
+//! `cargo test -p artifacts-mmo-api openapi -- --include-ignored --nocapture` to print the drift
+//! report.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::PathBuf,
+};
+
+use http::Method;
+use proptest::{
+    arbitrary::{any, Arbitrary},
+    strategy::{Strategy, ValueTree},
+    test_runner::TestRunner,
+};
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::{
+    schemas::*,
+    test_support::{endpoints, query_params, Endpoint},
+};
+
+const SPEC_ENV: &str = "ARTIFACTS_OPENAPI";
+const SPEC_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/openapi/artifacts-v1.3.json");
+/// Options are only serialized when set, so a field is seen once enough samples were drawn
+const SAMPLES: usize = 32;
+
+/// A request as built by the crate, stripped of its response type
+struct Operation {
+    name: &'static str,
+    method: Method,
+    path: String,
+    query: BTreeSet<String>,
+    body: BTreeSet<String>,
+}
+
+impl From<Endpoint> for Operation {
+    fn from(endpoint: Endpoint) -> Self {
+        let name = endpoint.name;
+        let query = match endpoint.path.query() {
+            Some(_) => query_params(&endpoint.path)
+                .into_iter()
+                .map(|(key, _)| key)
+                .collect(),
+            None => BTreeSet::new(),
+        };
+        let body = match endpoint.content.is_empty() {
+            true => BTreeSet::new(),
+            false => serde_json::from_slice::<Map<String, Value>>(&endpoint.content)
+                .unwrap_or_else(|error| panic!("{name}: {error}"))
+                .into_iter()
+                .map(|(key, _)| key)
+                .collect(),
+        };
+
+        Self {
+            name,
+            method: endpoint.method,
+            path: endpoint.path.path().to_string(),
+            query,
+            body,
+        }
+    }
+}
+
+fn operations() -> Vec<Operation> {
+    endpoints().into_iter().map(Operation::from).collect()
+}
+
+/// Serialized samples of a schema, drawn deterministically so that the report is stable
+fn samples<T: Arbitrary + Serialize>() -> Vec<Value> {
+    let mut runner = TestRunner::deterministic();
+    (0..SAMPLES)
+        .map(|_| {
+            let value = any::<T>().new_tree(&mut runner).unwrap().current();
+            serde_json::to_value(value).unwrap()
+        })
+        .collect()
+}
+
+fn schemas() -> Vec<(&'static str, Vec<Value>)> {
+    macro_rules! schemas {
+        ($($schema:ident),* $(,)?) => {
+            vec![$((stringify!($schema), samples::<$schema>())),*]
+        };
+    }
+
+    schemas![
+        AnnouncementSchema,
+        BankItemSchema,
+        BlockedHitsSchema,
+        CharacterFightDataSchema,
+        CharacterMovementDataSchema,
+        CharacterSchema,
+        CooldownSchema,
+        CraftSchema,
+        DeleteItemSchema,
+        DestinationResponseSchema,
+        DropRateSchema,
+        DropSchema,
+        EquipRequestSchema,
+        ErrorResponseSchema,
+        ErrorSchema,
+        EventSchema,
+        FightSchema,
+        GEItemSchema,
+        GETransactionListSchema,
+        GETransactionSchema,
+        GoldSchema,
+        GoldTransactionSchema,
+        InventorySlotSchema,
+        ItemEffectSchema,
+        ItemSchema,
+        LogSchema,
+        MapContentSchema,
+        MapSchema,
+        MessageSchema,
+        MonsterSchema,
+        RecyclingDataSchema,
+        RecyclingItemsSchema,
+        ResourceSchema,
+        SimpleItemSchema,
+        SingleItemSchema,
+        SkillDataSchema,
+        SkillInfoSchema,
+        StatusSchema,
+        TaskDataSchema,
+        TaskRewardDataSchema,
+        TaskRewardSchema,
+        TaskSchema,
+        TokenSchema,
+    ]
+}
+
+/// Whether a value of the spec is only understood as the `Unknown` fallback of the enum
+type IsUnknown = fn(&str) -> bool;
+
+fn enums() -> Vec<(&'static str, IsUnknown)> {
+    macro_rules! enums {
+        ($($schema:ident),* $(,)?) => {
+            vec![$((
+                stringify!($schema),
+                (|value| matches!(value.parse(), Ok($schema::Unknown(_)))) as IsUnknown,
+            )),*]
+        };
+    }
+
+    enums![
+        CraftSkillSchema,
+        ItemTypeSchema,
+        MapContentTypeSchema,
+        ReasonSchema,
+        SkillSchema,
+        SlotTypeSchema,
+        TaskTypeSchema,
+    ]
+}
+
+struct Spec<'a>(&'a Value);
+
+impl<'a> Spec<'a> {
+    fn schema(&self, name: &str) -> Option<&'a Value> {
+        let schemas = &self.0["components"]["schemas"];
+        schemas
+            .get(name)
+            .or_else(|| schemas.get(name.strip_suffix("Schema")?))
+    }
+
+    /// Follows `$ref` and single element `allOf`, the way FastAPI wraps referenced schemas
+    fn resolve(&self, schema: &'a Value) -> &'a Value {
+        if let Some(reference) = schema["$ref"].as_str() {
+            let name = reference.trim_start_matches("#/components/schemas/");
+            return self
+                .0
+                .pointer(&format!("/components/schemas/{name}"))
+                .map_or(schema, |schema| self.resolve(schema));
+        }
+        match schema["allOf"].as_array().map(Vec::as_slice) {
+            Some([schema]) => self.resolve(schema),
+            _ => schema,
+        }
+    }
+
+    fn properties(&self, schema: &'a Value) -> BTreeMap<&'a str, &'a Value> {
+        self.resolve(schema)["properties"]
+            .as_object()
+            .into_iter()
+            .flatten()
+            .map(|(key, value)| (key.as_str(), value))
+            .collect()
+    }
+
+    /// JSON types a schema accepts, empty when it accepts anything
+    fn types(&self, schema: &'a Value) -> BTreeSet<&'static str> {
+        let schema = self.resolve(schema);
+        let mut types = BTreeSet::new();
+        for key in ["anyOf", "oneOf", "allOf"] {
+            for schema in schema[key].as_array().into_iter().flatten() {
+                types.extend(self.types(schema));
+            }
+        }
+        let declared = match &schema["type"] {
+            Value::String(kind) => vec![kind.as_str()],
+            Value::Array(kinds) => kinds.iter().filter_map(Value::as_str).collect(),
+            _ if schema.get("enum").is_some() => vec!["string"],
+            _ if schema.get("properties").is_some() => vec!["object"],
+            _ => vec![],
+        };
+        types.extend(declared.into_iter().filter_map(|kind| match kind {
+            "integer" | "number" => Some("number"),
+            "string" => Some("string"),
+            "boolean" => Some("boolean"),
+            "array" => Some("array"),
+            "object" => Some("object"),
+            "null" => Some("null"),
+            _ => None,
+        }));
+        if schema["nullable"] == Value::Bool(true) {
+            types.insert("null");
+        }
+        types
+    }
+
+    fn operations(&self) -> Vec<(Method, &'a str, &'a Value)> {
+        let mut operations = Vec::new();
+        for (path, item) in self.0["paths"].as_object().into_iter().flatten() {
+            for (method, operation) in item.as_object().into_iter().flatten() {
+                if let Ok(method) = method.to_uppercase().parse::<Method>() {
+                    operations.push((method, path.as_str(), operation));
+                }
+            }
+        }
+        operations
+    }
+}
+
+fn json_type(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// Number of literal segments of `template` matched by `path`, `None` if it doesn't match
+fn match_path(template: &str, path: &str) -> Option<usize> {
+    let templates = template.split('/').collect::<Vec<_>>();
+    let segments = path.split('/').collect::<Vec<_>>();
+    if templates.len() != segments.len() {
+        return None;
+    }
+    templates
+        .iter()
+        .zip(&segments)
+        .try_fold(0, |literals, (template, segment)| {
+            match template.starts_with('{') && template.ends_with('}') {
+                true => Some(literals),
+                false => (template == segment).then_some(literals + 1),
+            }
+        })
+}
+
+fn difference<'b>(
+    report: &mut Vec<String>,
+    context: &str,
+    spec: &BTreeSet<&'b str>,
+    crate_side: &BTreeSet<&'b str>,
+) {
+    for key in spec.difference(crate_side) {
+        report.push(format!("{context} `{key}` is missing from the crate"));
+    }
+    for key in crate_side.difference(spec) {
+        report.push(format!("{context} `{key}` is not in the spec"));
+    }
+}
+
+fn check_operations(spec: &Value, operations: &[Operation]) -> Vec<String> {
+    let spec = Spec(spec);
+    let mut report = Vec::new();
+    let mut matched = BTreeSet::new();
+
+    for (method, template, operation) in spec.operations() {
+        let Some((index, crate_operation)) = operations
+            .iter()
+            .enumerate()
+            .filter(|(_, operation)| operation.method == method)
+            .filter_map(|(index, operation)| {
+                let literals = match_path(template, &operation.path)?;
+                // Only the most specific template of the spec may claim a builder
+                let best = spec
+                    .operations()
+                    .iter()
+                    .filter(|(other, _, _)| *other == method)
+                    .filter_map(|(_, other, _)| match_path(other, &operation.path))
+                    .max()?;
+                (literals == best).then_some((index, operation))
+            })
+            .next()
+        else {
+            report.push(format!("{method} {template}: no builder in the crate"));
+            continue;
+        };
+        matched.insert(index);
+        let context = format!("{method} {template} ({})", crate_operation.name);
+
+        let query = operation["parameters"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(|parameter| spec.resolve(parameter))
+            .filter(|parameter| parameter["in"] == "query")
+            .filter_map(|parameter| parameter["name"].as_str())
+            .collect();
+        let crate_query = crate_operation.query.iter().map(String::as_str).collect();
+        difference(
+            &mut report,
+            &format!("{context} query param"),
+            &query,
+            &crate_query,
+        );
+
+        let body = operation["requestBody"]["content"]
+            .as_object()
+            .and_then(|content| content.get("application/json"))
+            .map(|content| spec.properties(&content["schema"]).into_keys().collect())
+            .unwrap_or_default();
+        let crate_body = crate_operation.body.iter().map(String::as_str).collect();
+        difference(
+            &mut report,
+            &format!("{context} body field"),
+            &body,
+            &crate_body,
+        );
+    }
+
+    for (index, operation) in operations.iter().enumerate() {
+        if !matched.contains(&index) {
+            report.push(format!(
+                "{} {} ({}): not in the spec",
+                operation.method, operation.path, operation.name
+            ));
+        }
+    }
+    report
+}
+
+fn check_schemas(spec: &Value, schemas: &[(&str, Vec<Value>)]) -> Vec<String> {
+    let spec = Spec(spec);
+    let mut report = BTreeSet::new();
+
+    for (name, samples) in schemas {
+        let Some(schema) = spec.schema(name) else {
+            report.insert(format!("{name}: not in the spec"));
+            continue;
+        };
+        let properties = spec.properties(schema);
+        let mut seen = BTreeSet::new();
+
+        for (key, value) in samples.iter().filter_map(Value::as_object).flatten() {
+            seen.insert(key.as_str());
+            let Some(property) = properties.get(key.as_str()) else {
+                continue;
+            };
+            let types = spec.types(property);
+            let kind = json_type(value);
+            if types.is_empty() || types.contains(kind) {
+                continue;
+            }
+            report.insert(match kind {
+                "null" => {
+                    format!("{name}.{key}: optional in the crate but not nullable in the spec")
+                }
+                _ => format!("{name}.{key}: crate sends {kind} but the spec expects {types:?}"),
+            });
+        }
+
+        let mut fields = Vec::new();
+        difference(
+            &mut fields,
+            &format!("{name} field"),
+            &properties.keys().copied().collect(),
+            &seen,
+        );
+        report.extend(fields);
+    }
+    report.into_iter().collect()
+}
+
+fn check_enums(spec: &Value, enums: &[(&str, IsUnknown)]) -> Vec<String> {
+    let spec = Spec(spec);
+    let mut report = Vec::new();
+
+    for (name, is_unknown) in enums {
+        let Some(schema) = spec.schema(name) else {
+            report.push(format!("{name}: not in the spec"));
+            continue;
+        };
+        for value in spec.resolve(schema)["enum"]
+            .as_array()
+            .into_iter()
+            .flatten()
+        {
+            if let Some(value) = value.as_str().filter(|value| is_unknown(value)) {
+                report.push(format!("{name}: `{value}` falls back to `Unknown`"));
+            }
+        }
+    }
+    report
+}
+
+fn drift_report(spec: &Value) -> Vec<String> {
+    let mut report = check_operations(spec, &operations());
+    report.extend(check_schemas(spec, &schemas()));
+    report.extend(check_enums(spec, &enums()));
+    report
+}
+
+#[test]
+#[ignore = "needs openapi/artifacts-v1.3.json vendored from the API, see the module docs"]
+fn crate_should_match_the_vendored_spec() {
+    let path = std::env::var_os(SPEC_ENV).map_or_else(|| PathBuf::from(SPEC_PATH), PathBuf::from);
+    let content = std::fs::read(&path)
+        .unwrap_or_else(|error| panic!("no OpenAPI spec at {}: {error}", path.display()));
+    let spec = serde_json::from_slice(&content).unwrap();
+
+    let report = drift_report(&spec);
+    assert!(
+        report.is_empty(),
+        "{} drift(s) from {}:\n{}",
+        report.len(),
+        path.display(),
+        report.join("\n")
+    );
+}
+
+/// Hand-written excerpt shaped like the FastAPI output, not a copy of the official spec
+const FIXTURE: &str = r##"{
+    "paths": {
+        "/maps/{x}/{y}": {
+            "get": {"parameters": [{"name": "x", "in": "path"}, {"name": "y", "in": "path"}]}
+        },
+        "/maps/": {
+            "get": {"parameters": [
+                {"name": "content_type", "in": "query"},
+                {"name": "content_code", "in": "query"},
+                {"name": "page", "in": "query"},
+                {"name": "size", "in": "query"},
+                {"name": "region", "in": "query"}
+            ]}
+        },
+        "/my/{name}/action/move": {
+            "post": {"requestBody": {"content": {"application/json": {
+                "schema": {"$ref": "#/components/schemas/DestinationSchema"}
+            }}}}
+        },
+        "/my/{name}/action/teleport": {"post": {}}
+    },
+    "components": {"schemas": {
+        "DestinationSchema": {"properties": {"x": {"type": "integer"}, "z": {"type": "integer"}}},
+        "MapContentSchema": {"properties": {"type": {"type": "string"}, "code": {"type": "string"}}},
+        "MapSchema": {"properties": {
+            "name": {"type": "string"},
+            "skin": {"type": "string"},
+            "x": {"type": "string"},
+            "y": {"type": "integer"},
+            "region": {"type": "string"},
+            "content": {"anyOf": [{"$ref": "#/components/schemas/MapContentSchema"}, {"type": "null"}]}
+        }},
+        "GoldSchema": {"properties": {"quantity": {"type": "integer"}}},
+        "SkillSchema": {"type": "string", "enum": ["mining", "woodcutting", "fishing", "alchemy"]}
+    }}
+}"##;
+
+#[test]
+fn check_operations_should_report_drift() {
+    let spec = serde_json::from_str(FIXTURE).unwrap();
+    let operations = operations()
+        .into_iter()
+        .filter(|operation| {
+            ["get_map", "get_all_maps", "action_move", "action_fight"].contains(&operation.name)
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        check_operations(&spec, &operations),
+        [
+            "GET /maps/ (get_all_maps) query param `region` is missing from the crate",
+            "POST /my/{name}/action/move (action_move) body field `z` is missing from the crate",
+            "POST /my/{name}/action/move (action_move) body field `y` is not in the spec",
+            "POST /my/{name}/action/teleport: no builder in the crate",
+            "POST /my/Bob/action/fight (action_fight): not in the spec",
+        ]
+    );
+}
+
+#[test]
+fn check_schemas_should_report_drift() {
+    let spec = serde_json::from_str(FIXTURE).unwrap();
+
+    assert_eq!(
+        check_schemas(
+            &spec,
+            &[
+                ("MapSchema", samples::<MapSchema>()),
+                ("GoldSchema", samples::<GoldSchema>()),
+                ("TokenSchema", samples::<TokenSchema>()),
+            ]
+        ),
+        [
+            "MapSchema field `region` is missing from the crate",
+            "MapSchema.x: crate sends number but the spec expects {\"string\"}",
+            "TokenSchema: not in the spec",
+        ]
+    );
+}
+
+#[test]
+fn check_enums_should_report_unknown_values() {
+    let spec = serde_json::from_str(FIXTURE).unwrap();
+
+    assert_eq!(
+        check_enums(&spec, &enums()[4..=5]),
+        [
+            "SkillSchema: `alchemy` falls back to `Unknown`",
+            "SlotTypeSchema: not in the spec",
+        ]
+    );
+}
+
+#[test]
+fn match_path_should_prefer_literal_segments() {
+    assert_eq!(match_path("/maps/{x}/{y}", "/maps/-1/2"), Some(2));
+    assert_eq!(match_path("/my/bank/gold", "/my/bank/gold"), Some(4));
+    assert_eq!(match_path("/my/{name}/gold", "/my/bank/gold"), Some(3));
+    assert_eq!(match_path("/items/", "/items/copper"), None);
+}
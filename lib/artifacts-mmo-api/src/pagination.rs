@@ -0,0 +1,97 @@
+//! Walk every page of the paginated endpoints without doing any IO
+
+use crate::{schemas::PaginatedResponseSchema, EncodedRequest};
+
+/// Request of an endpoint answering with a `PaginatedResponseSchema`
+pub trait PaginatedRequest: Clone + Sized {
+    fn page(&self) -> u32;
+    fn set_page(&mut self, page: u32);
+    fn encode(self) -> Result<EncodedRequest<Self>, crate::Error>;
+}
+
+/// Produce the request of each page from the response of the previous one
+#[derive(Debug, Clone)]
+pub struct Paginator<R> {
+    request: R,
+    done: bool,
+}
+
+impl<R: PaginatedRequest> Paginator<R> {
+    /// Start from the page set on `request`
+    pub fn new(request: R) -> Self {
+        Self {
+            request,
+            done: false,
+        }
+    }
+
+    /// Request of the current page, `None` once the last page has been received
+    pub fn next_request(&self) -> Option<Result<EncodedRequest<R>, crate::Error>> {
+        if self.done {
+            return None;
+        }
+
+        Some(self.request.clone().encode())
+    }
+
+    /// Move to the page following `response`
+    pub fn advance<T>(&mut self, response: &PaginatedResponseSchema<T>) {
+        if response.page >= response.pages || response.data.is_empty() {
+            self.done = true;
+        } else {
+            self.request.set_page(response.page + 1);
+        }
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Paginator;
+    use crate::{endpoints::GetAllItemsRequest, schemas::PaginatedResponseSchema};
+
+    fn response(page: u32, pages: u32) -> PaginatedResponseSchema<u32> {
+        PaginatedResponseSchema {
+            data: vec![page],
+            total: pages,
+            page,
+            size: 1,
+            pages,
+        }
+    }
+
+    #[test]
+    fn paginator_should_request_every_page() {
+        let mut paginator = Paginator::new(GetAllItemsRequest::builder().size(1).build());
+        let mut paths = Vec::new();
+
+        while let Some(request) = paginator.next_request() {
+            let request = request.unwrap();
+            paths.push(request.path.to_string());
+            let page = paths.len() as u32;
+            paginator.advance(&response(page, 3));
+        }
+
+        assert_eq!(paths.len(), 3);
+        assert!(paths[0].contains("page=1"));
+        assert!(paths[2].contains("page=3"));
+        assert!(paginator.is_done());
+    }
+
+    #[test]
+    fn paginator_should_stop_on_empty_results() {
+        let mut paginator = Paginator::new(GetAllItemsRequest::builder().build());
+        paginator.advance(&PaginatedResponseSchema::<u32> {
+            data: Vec::new(),
+            total: 0,
+            page: 1,
+            size: 50,
+            pages: 0,
+        });
+
+        assert!(paginator.next_request().is_none());
+    }
+}
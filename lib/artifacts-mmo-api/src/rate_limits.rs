@@ -1,6 +1,13 @@
 //! Rate limits for the API
 //! SOURCE: <https://docs.artifactsmmo.com/api_guide/rate_limits>
 
+use std::{
+    collections::{HashMap, VecDeque},
+    time::{Duration, Instant},
+};
+
+use crate::EncodedRequest;
+
 #[derive(Debug, Clone)]
 pub struct RateLimit<'a> {
     pub id: &'static str,
@@ -14,6 +21,21 @@ pub enum LimitPer {
     Seconds(u32),
 }
 
+impl LimitPer {
+    pub fn window(&self) -> Duration {
+        match self {
+            LimitPer::Hours(_) => Duration::from_secs(60 * 60),
+            LimitPer::Seconds(_) => Duration::from_secs(1),
+        }
+    }
+
+    pub fn limit(&self) -> u32 {
+        match self {
+            LimitPer::Hours(limit) | LimitPer::Seconds(limit) => *limit,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum RateLimitBy {
     Ip,
@@ -46,3 +68,213 @@ pub const NO_RATE_LIMIT: RateLimit<'_> = RateLimit {
     by: RateLimitBy::Ip,
     requests_limits: &[],
 };
+
+/// Source of time for the [`Limiter`], replace it to control time in tests
+pub trait Clock {
+    fn now(&self) -> Instant;
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Permit {
+    /// The request can be sent now, it has been counted against its rate limit
+    Go,
+    /// Sending the request now would exceed its rate limit
+    WaitUntil(Instant),
+}
+
+/// Client-side sliding window limiter, usage is tracked per [`RateLimit::id`] so requests sharing
+/// a rate limit share their quota. It never sleeps, the caller decides how to wait.
+#[derive(Debug, Default)]
+pub struct Limiter<C = SystemClock> {
+    clock: C,
+    usage: HashMap<&'static str, VecDeque<Instant>>,
+}
+
+impl Limiter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<C: Clock> Limiter<C> {
+    pub fn with_clock(clock: C) -> Self {
+        Self {
+            clock,
+            usage: HashMap::new(),
+        }
+    }
+
+    pub fn acquire<T>(&mut self, request: &EncodedRequest<T>) -> Permit {
+        self.acquire_rate_limit(&request.rate_limit)
+    }
+
+    pub fn acquire_rate_limit(&mut self, rate_limit: &RateLimit<'_>) -> Permit {
+        let Some(longest_window) = rate_limit
+            .requests_limits
+            .iter()
+            .map(LimitPer::window)
+            .max()
+        else {
+            return Permit::Go;
+        };
+
+        let now = self.clock.now();
+        let usage = self.usage.entry(rate_limit.id).or_default();
+        // Requests older than every window can't count against the limit anymore
+        while usage
+            .front()
+            .is_some_and(|sent_at| now.duration_since(*sent_at) >= longest_window)
+        {
+            usage.pop_front();
+        }
+
+        let wait_until = rate_limit
+            .requests_limits
+            .iter()
+            .filter_map(|limit| {
+                let in_window = usage
+                    .iter()
+                    .filter(|sent_at| now.duration_since(**sent_at) < limit.window())
+                    .collect::<Vec<_>>();
+                let over_limit = (in_window.len() + 1).checked_sub(limit.limit() as usize)?;
+                if over_limit == 0 {
+                    return None;
+                }
+                // The window frees up once enough of its oldest requests have expired
+                in_window
+                    .get(over_limit - 1)
+                    .map(|sent_at| **sent_at + limit.window())
+                    .or(Some(now + limit.window()))
+            })
+            .max();
+
+        match wait_until {
+            Some(wait_until) => Permit::WaitUntil(wait_until),
+            None => {
+                usage.push_back(now);
+                Permit::Go
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::Cell,
+        rc::Rc,
+        time::{Duration, Instant},
+    };
+
+    use super::{
+        Clock, LimitPer, Limiter, Permit, RateLimit, RateLimitBy, ACTIONS_RATE_LIMIT,
+        DATA_RATE_LIMIT, NO_RATE_LIMIT,
+    };
+
+    #[derive(Clone)]
+    struct FakeClock {
+        start: Instant,
+        elapsed: Rc<Cell<Duration>>,
+    }
+
+    impl FakeClock {
+        fn new() -> Self {
+            Self {
+                start: Instant::now(),
+                elapsed: Rc::new(Cell::new(Duration::ZERO)),
+            }
+        }
+
+        fn advance(&self, duration: Duration) {
+            self.elapsed.set(self.elapsed.get() + duration);
+        }
+    }
+
+    impl Clock for FakeClock {
+        fn now(&self) -> Instant {
+            self.start + self.elapsed.get()
+        }
+    }
+
+    #[test]
+    fn limiter_should_allow_up_to_the_limit() {
+        let clock = FakeClock::new();
+        let mut limiter = Limiter::with_clock(clock.clone());
+
+        for _ in 0..5 {
+            assert_eq!(limiter.acquire_rate_limit(&ACTIONS_RATE_LIMIT), Permit::Go);
+        }
+        assert_eq!(
+            limiter.acquire_rate_limit(&ACTIONS_RATE_LIMIT),
+            Permit::WaitUntil(clock.now() + Duration::from_secs(1))
+        );
+    }
+
+    #[test]
+    fn limiter_should_release_after_the_window() {
+        let clock = FakeClock::new();
+        let mut limiter = Limiter::with_clock(clock.clone());
+
+        for _ in 0..5 {
+            assert_eq!(limiter.acquire_rate_limit(&ACTIONS_RATE_LIMIT), Permit::Go);
+            clock.advance(Duration::from_millis(100));
+        }
+        // The first request was sent 500ms ago, it expires in 500ms
+        let Permit::WaitUntil(wait_until) = limiter.acquire_rate_limit(&ACTIONS_RATE_LIMIT) else {
+            panic!("limit should be reached");
+        };
+        assert_eq!(wait_until, clock.now() + Duration::from_millis(500));
+
+        clock.advance(Duration::from_millis(500));
+        assert_eq!(limiter.acquire_rate_limit(&ACTIONS_RATE_LIMIT), Permit::Go);
+    }
+
+    #[test]
+    fn limiter_should_track_rate_limits_separately() {
+        let clock = FakeClock::new();
+        let mut limiter = Limiter::with_clock(clock);
+
+        for _ in 0..5 {
+            assert_eq!(limiter.acquire_rate_limit(&ACTIONS_RATE_LIMIT), Permit::Go);
+        }
+        assert_eq!(limiter.acquire_rate_limit(&DATA_RATE_LIMIT), Permit::Go);
+    }
+
+    #[test]
+    fn limiter_should_enforce_every_window() {
+        let clock = FakeClock::new();
+        let mut limiter = Limiter::with_clock(clock.clone());
+        let rate_limit = RateLimit {
+            id: "TEST",
+            by: RateLimitBy::Ip,
+            requests_limits: &[LimitPer::Seconds(2), LimitPer::Hours(3)],
+        };
+
+        for _ in 0..3 {
+            assert_eq!(limiter.acquire_rate_limit(&rate_limit), Permit::Go);
+            clock.advance(Duration::from_secs(1));
+        }
+        assert_eq!(
+            limiter.acquire_rate_limit(&rate_limit),
+            Permit::WaitUntil(clock.now() - Duration::from_secs(3) + Duration::from_secs(3600))
+        );
+    }
+
+    #[test]
+    fn limiter_should_always_allow_without_rate_limit() {
+        let mut limiter = Limiter::with_clock(FakeClock::new());
+
+        for _ in 0..1000 {
+            assert_eq!(limiter.acquire_rate_limit(&NO_RATE_LIMIT), Permit::Go);
+        }
+    }
+}
@@ -1,9 +1,11 @@
 use chrono::{DateTime, Utc};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// SOURCE: <https://api.artifactsmmo.com/docs/#/operations/get_status__get>
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(test, derive(proptest_derive::Arbitrary))]
 pub struct AnnouncementSchema {
     pub message: String,
+    #[cfg_attr(test, proptest(strategy = "super::tests::datetime()"))]
     pub created_at: DateTime<Utc>,
 }
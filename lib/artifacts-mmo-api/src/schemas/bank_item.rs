@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+use super::{CharacterSchema, CooldownSchema, ItemSchema, SimpleItemSchema};
+
+/// SOURCE: <https://api.artifactsmmo.com/docs/#/operations/action_deposit_bank_my__name__action_bank_deposit_post>
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(test, derive(proptest_derive::Arbitrary))]
+pub struct BankItemSchema {
+    pub cooldown: CooldownSchema,
+    pub item: ItemSchema,
+    pub bank: Vec<SimpleItemSchema>,
+    pub character: CharacterSchema,
+}
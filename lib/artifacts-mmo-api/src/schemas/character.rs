@@ -1,9 +1,11 @@
 use chrono::{DateTime, Utc};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-use super::InventorySlotSchema;
+use super::{InventorySlotSchema, TaskTypeSchema};
+use crate::helpers::{empty_string_as_none, none_as_empty_string};
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(test, derive(proptest_derive::Arbitrary))]
 pub struct CharacterSchema {
     pub name: String,
     pub skin: String,
@@ -48,9 +50,10 @@ pub struct CharacterSchema {
     pub res_earth: u32,
     pub res_water: u32,
     pub res_air: u32,
-    pub x: u32,
-    pub y: u32,
+    pub x: i32,
+    pub y: i32,
     pub cooldown: u32,
+    #[cfg_attr(test, proptest(strategy = "super::tests::datetime()"))]
     pub cooldown_expiration: DateTime<Utc>,
     pub weapon_slot: String,
     pub shield_slot: String,
@@ -69,7 +72,12 @@ pub struct CharacterSchema {
     pub consumable2_slot: String,
     pub consumable2_slot_quantity: u32,
     pub task: String,
-    pub task_type: String,
+    // The API sends an empty string when the character has no task
+    #[serde(
+        deserialize_with = "empty_string_as_none",
+        serialize_with = "none_as_empty_string"
+    )]
+    pub task_type: Option<TaskTypeSchema>,
     pub task_progress: u32,
     pub task_total: u32,
     pub inventory_max_items: u32,
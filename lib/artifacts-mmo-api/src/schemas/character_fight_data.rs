@@ -1,8 +1,9 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use super::{CharacterSchema, CooldownSchema, FightSchema};
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(test, derive(proptest_derive::Arbitrary))]
 pub struct CharacterFightDataSchema {
     pub cooldown: CooldownSchema,
     pub fight: FightSchema,
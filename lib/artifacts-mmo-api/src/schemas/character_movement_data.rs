@@ -1,8 +1,9 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use super::{CharacterSchema, CooldownSchema, DestinationResponseSchema};
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(test, derive(proptest_derive::Arbitrary))]
 pub struct CharacterMovementDataSchema {
     pub cooldown: CooldownSchema,
     pub destination: DestinationResponseSchema,
@@ -1,13 +1,16 @@
 use chrono::{DateTime, Utc};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use super::{CharacterSchema, ReasonSchema};
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(test, derive(proptest_derive::Arbitrary))]
 pub struct CooldownSchema {
     pub total_seconds: u32,
     pub remaining_seconds: u32,
+    #[cfg_attr(test, proptest(strategy = "super::tests::datetime()"))]
     pub started_at: DateTime<Utc>,
+    #[cfg_attr(test, proptest(strategy = "super::tests::datetime()"))]
     pub expiration: DateTime<Utc>,
     pub reason: ReasonSchema,
 }
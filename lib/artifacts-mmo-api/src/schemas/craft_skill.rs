@@ -1,8 +1,8 @@
-use serde::Deserialize;
-use strum::Display;
+use strum::{Display, EnumString};
 
 /// SOURCE: <https://api.artifactsmmo.com/docs/#/operations/get_all_items_items__get>
-#[derive(Debug, Clone, Deserialize, Display)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Display, EnumString)]
+#[cfg_attr(test, derive(proptest_derive::Arbitrary))]
 #[strum(serialize_all = "lowercase")]
 pub enum CraftSkillSchema {
     WeaponCrafting,
@@ -11,5 +11,8 @@ pub enum CraftSkillSchema {
     Cooking,
     Woodcutting,
     Mining,
+    #[strum(default)]
     Unknown(String),
 }
+
+impl_serde_from_str!(CraftSkillSchema);
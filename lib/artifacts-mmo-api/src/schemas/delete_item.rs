@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use super::{CharacterSchema, CooldownSchema, SimpleItemSchema};
+
+/// SOURCE: <https://api.artifactsmmo.com/docs/#/operations/action_delete_item_my__name__action_delete_post>
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(test, derive(proptest_derive::Arbitrary))]
+pub struct DeleteItemSchema {
+    pub cooldown: CooldownSchema,
+    pub item: SimpleItemSchema,
+    pub character: CharacterSchema,
+}
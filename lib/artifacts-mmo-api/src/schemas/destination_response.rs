@@ -1,10 +1,11 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use super::CharacterSchema;
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(test, derive(proptest_derive::Arbitrary))]
 pub struct DestinationResponseSchema {
     pub name: String,
-    pub x: u32,
-    pub y: u32,
+    pub x: i32,
+    pub y: i32,
 }
@@ -1,7 +1,8 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// SOURCE: <https://api.artifactsmmo.com/docs/#/operations/get_resources_resources__code__get>
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(test, derive(proptest_derive::Arbitrary))]
 pub struct DropRateSchema {
     pub code: String,
     pub rate: u32,
@@ -1,8 +1,9 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use super::{CharacterSchema, CooldownSchema, ItemSchema, SlotTypeSchema};
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(test, derive(proptest_derive::Arbitrary))]
 pub struct EquipRequestSchema {
     pub cooldown: CooldownSchema,
     pub slot: SlotTypeSchema,
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// SOURCE: <https://docs.artifactsmmo.com/api_guide/response_codes>
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(test, derive(proptest_derive::Arbitrary))]
+pub struct ErrorResponseSchema {
+    pub error: ErrorSchema,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(test, derive(proptest_derive::Arbitrary))]
+pub struct ErrorSchema {
+    pub code: u16,
+    pub message: String,
+}
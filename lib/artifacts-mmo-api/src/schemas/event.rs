@@ -1,12 +1,15 @@
 use chrono::{DateTime, Utc};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// SOURCE: <https://api.artifactsmmo.com/docs/#/operations/get_resources_resources__code__get>
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(test, derive(proptest_derive::Arbitrary))]
 pub struct EventSchema {
     pub name: String,
     pub previous_skin: String,
     pub duration: u32,
+    #[cfg_attr(test, proptest(strategy = "super::tests::datetime()"))]
     pub expiration: DateTime<Utc>,
+    #[cfg_attr(test, proptest(strategy = "super::tests::datetime()"))]
     pub created_at: DateTime<Utc>,
 }
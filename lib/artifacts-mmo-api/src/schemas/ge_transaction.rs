@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+
+use super::GEItemSchema;
+
+/// SOURCE: <https://api.artifactsmmo.com/docs/#/operations/action_ge_buy_item_my__name__action_ge_buy_post>
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(test, derive(proptest_derive::Arbitrary))]
+pub struct GETransactionSchema {
+    pub code: String,
+    pub quantity: u32,
+    pub price: u32,
+    pub total_price: u32,
+}
+
+impl GETransactionSchema {
+    /// Buying `quantity` items of the listing at its `buy_price`, `None` if the total overflows
+    pub fn buy(listing: &GEItemSchema, quantity: u32) -> Option<Self> {
+        Self::new(listing, quantity, listing.buy_price)
+    }
+
+    /// Selling `quantity` items to the listing at its `sell_price`, `None` if the total overflows
+    pub fn sell(listing: &GEItemSchema, quantity: u32) -> Option<Self> {
+        Self::new(listing, quantity, listing.sell_price)
+    }
+
+    fn new(listing: &GEItemSchema, quantity: u32, price: u32) -> Option<Self> {
+        Some(Self {
+            code: listing.code.clone(),
+            quantity,
+            price,
+            total_price: price.checked_mul(quantity)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::GETransactionSchema;
+    use crate::schemas::GEItemSchema;
+
+    proptest! {
+        #[test]
+        fn transaction_should_take_the_listing_price(
+            listing in any::<GEItemSchema>(),
+            quantity in any::<u32>(),
+        ) {
+            match GETransactionSchema::buy(&listing, quantity) {
+                Some(transaction) => {
+                    prop_assert_eq!(&transaction.code, &listing.code);
+                    prop_assert_eq!(transaction.price, listing.buy_price);
+                    prop_assert_eq!(
+                        u64::from(transaction.total_price),
+                        u64::from(listing.buy_price) * u64::from(quantity)
+                    );
+                }
+                None => prop_assert!(listing.buy_price.checked_mul(quantity).is_none()),
+            }
+            let sell = GETransactionSchema::sell(&listing, 1).unwrap();
+            prop_assert_eq!(sell.total_price, listing.sell_price);
+        }
+    }
+}
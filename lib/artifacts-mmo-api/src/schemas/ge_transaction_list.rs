@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use super::{CharacterSchema, CooldownSchema, GETransactionSchema};
+
+/// SOURCE: <https://api.artifactsmmo.com/docs/#/operations/action_ge_buy_item_my__name__action_ge_buy_post>
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(test, derive(proptest_derive::Arbitrary))]
+pub struct GETransactionListSchema {
+    pub cooldown: CooldownSchema,
+    pub transaction: GETransactionSchema,
+    pub character: CharacterSchema,
+}
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use super::{CharacterSchema, CooldownSchema, GoldSchema};
+
+/// SOURCE: <https://api.artifactsmmo.com/docs/#/operations/action_deposit_bank_gold_my__name__action_bank_deposit_gold_post>
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(test, derive(proptest_derive::Arbitrary))]
+pub struct GoldTransactionSchema {
+    pub cooldown: CooldownSchema,
+    pub bank: GoldSchema,
+    pub character: CharacterSchema,
+}
@@ -1,8 +1,8 @@
-use serde::Deserialize;
-use strum::Display;
+use strum::{Display, EnumString};
 
 /// SOURCE: <https://api.artifactsmmo.com/docs/#/operations/get_all_items_items__get>
-#[derive(Debug, Clone, Deserialize, Display)]
+#[derive(Debug, Clone, PartialEq, Eq, Display, EnumString)]
+#[cfg_attr(test, derive(proptest_derive::Arbitrary))]
 #[strum(serialize_all = "snake_case")]
 pub enum ItemTypeSchema {
     Consumable,
@@ -10,10 +10,14 @@ pub enum ItemTypeSchema {
     Weapon,
     Resource,
     LegArmor,
-    Helment,
+    Helmet,
     Boots,
     Shield,
     Amulet,
     Ring,
+    Artifact,
+    #[strum(default)]
     Unknown(String),
 }
+
+impl_serde_from_str!(ItemTypeSchema);
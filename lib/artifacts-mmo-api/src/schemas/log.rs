@@ -0,0 +1,29 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::LogContentSchema;
+
+/// SOURCE: <https://api.artifactsmmo.com/docs/#/operations/get_all_characters_logs_my_logs_get>
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(test, derive(proptest_derive::Arbitrary))]
+pub struct LogSchema {
+    pub character: String,
+    pub account: String,
+    /// `type` and `content` of the log, the shape of the content depends on the type
+    #[serde(flatten)]
+    // Boxed, generating the content inline overflows the test thread's stack
+    #[cfg_attr(
+        test,
+        proptest(strategy = "proptest::strategy::Strategy::boxed(proptest::arbitrary::any::<LogContentSchema>())")
+    )]
+    pub content: LogContentSchema,
+    pub description: String,
+    pub cooldown: u32,
+    #[cfg_attr(
+        test,
+        proptest(strategy = "proptest::option::of(super::tests::datetime())")
+    )]
+    pub cooldown_expiration: Option<DateTime<Utc>>,
+    #[cfg_attr(test, proptest(strategy = "super::tests::datetime()"))]
+    pub created_at: DateTime<Utc>,
+}
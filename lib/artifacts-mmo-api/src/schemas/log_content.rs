@@ -0,0 +1,184 @@
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+use super::{
+    DestinationResponseSchema, FightSchema, GETransactionSchema, ItemSchema, ReasonSchema,
+    RecyclingItemsSchema, SimpleItemSchema, SkillInfoSchema, SlotTypeSchema, TaskSchema,
+};
+
+/// Content of a log keyed on its type, which takes the values of [`ReasonSchema`]: every action
+/// that puts a character in cooldown is logged with the details of the action's response
+/// SOURCE: <https://api.artifactsmmo.com/docs/#/operations/get_all_characters_logs_my_logs_get>
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(test, derive(proptest_derive::Arbitrary))]
+// `remote = "Self"` derives inherent functions wrapped by the trait impls below
+#[serde(
+    tag = "type",
+    content = "content",
+    rename_all = "snake_case",
+    remote = "Self"
+)]
+pub enum LogContentSchema {
+    Movement {
+        destination: DestinationResponseSchema,
+    },
+    Fight {
+        fight: FightSchema,
+    },
+    Crafting {
+        details: SkillInfoSchema,
+    },
+    Gathering {
+        details: SkillInfoSchema,
+    },
+    BuyGe {
+        transaction: GETransactionSchema,
+    },
+    SellGe {
+        transaction: GETransactionSchema,
+    },
+    DeleteItem {
+        item: SimpleItemSchema,
+    },
+    DepositBank {
+        item: ItemSchema,
+    },
+    WithdrawBank {
+        item: ItemSchema,
+    },
+    Equip {
+        slot: SlotTypeSchema,
+        item: ItemSchema,
+    },
+    Unequip {
+        slot: SlotTypeSchema,
+        item: ItemSchema,
+    },
+    Task {
+        task: TaskSchema,
+    },
+    Recycling {
+        details: RecyclingItemsSchema,
+    },
+    /// Type unknown to this crate, always a [`ReasonSchema::Unknown`]
+    #[serde(untagged, skip_deserializing)]
+    Unknown {
+        #[cfg_attr(test, proptest(strategy = "tests::unknown_reason()"))]
+        r#type: ReasonSchema,
+        #[cfg_attr(test, proptest(strategy = "super::tests::json_value()"))]
+        content: Value,
+    },
+}
+
+impl Serialize for LogContentSchema {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        LogContentSchema::serialize(self, serializer)
+    }
+}
+
+/// Content of a known type that doesn't decode is an error rather than [`LogContentSchema::Unknown`]
+impl<'de> Deserialize<'de> for LogContentSchema {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let log = Value::deserialize(deserializer)?;
+        let r#type = log.get("type").ok_or_else(|| D::Error::missing_field("type"))?;
+        match ReasonSchema::deserialize(r#type).map_err(D::Error::custom)? {
+            r#type @ ReasonSchema::Unknown(_) => Ok(LogContentSchema::Unknown {
+                r#type,
+                content: log.get("content").cloned().unwrap_or_default(),
+            }),
+            _ => LogContentSchema::deserialize(log).map_err(D::Error::custom),
+        }
+    }
+}
+
+impl LogContentSchema {
+    pub fn reason(&self) -> ReasonSchema {
+        match self {
+            LogContentSchema::Movement { .. } => ReasonSchema::Movement,
+            LogContentSchema::Fight { .. } => ReasonSchema::Fight,
+            LogContentSchema::Crafting { .. } => ReasonSchema::Crafting,
+            LogContentSchema::Gathering { .. } => ReasonSchema::Gathering,
+            LogContentSchema::BuyGe { .. } => ReasonSchema::BuyGe,
+            LogContentSchema::SellGe { .. } => ReasonSchema::SellGe,
+            LogContentSchema::DeleteItem { .. } => ReasonSchema::DeleteItem,
+            LogContentSchema::DepositBank { .. } => ReasonSchema::DepositBank,
+            LogContentSchema::WithdrawBank { .. } => ReasonSchema::WithdrawBank,
+            LogContentSchema::Equip { .. } => ReasonSchema::Equip,
+            LogContentSchema::Unequip { .. } => ReasonSchema::Unequip,
+            LogContentSchema::Task { .. } => ReasonSchema::Task,
+            LogContentSchema::Recycling { .. } => ReasonSchema::Recycling,
+            LogContentSchema::Unknown { r#type, .. } => r#type.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use serde_json::json;
+
+    use super::LogContentSchema;
+    use crate::schemas::{LogSchema, ReasonSchema};
+
+    fn log(r#type: &str, content: serde_json::Value) -> LogSchema {
+        serde_json::from_value(json!({
+            "character": "Bob",
+            "account": "bobby_1",
+            "type": r#type,
+            "description": "Deposit 1 copper",
+            "content": content,
+            "cooldown": 3,
+            "cooldown_expiration": null,
+            "created_at": "2024-08-01T12:00:00Z"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn content_should_be_decoded_from_the_type() {
+        let log = log(
+            "delete_item",
+            json!({"item": {"code": "copper", "quantity": 1}}),
+        );
+        assert!(
+            matches!(log.content, LogContentSchema::DeleteItem { item } if item.code == "copper")
+        );
+    }
+
+    #[test]
+    fn unknown_type_should_fall_back_to_unknown() {
+        let content = json!({"season": 2});
+        let unseen = log("added_in_next_season", content.clone());
+        assert_eq!(
+            unseen.content.reason(),
+            ReasonSchema::Unknown("added_in_next_season".to_string())
+        );
+        assert!(matches!(unseen.content, LogContentSchema::Unknown { content: ref unseen, .. } if *unseen == content));
+    }
+
+    #[test]
+    fn unexpected_content_should_be_an_error() {
+        let content = json!({
+            "type": "deposit_bank",
+            "content": {"gold": {"quantity": 10}}
+        });
+        assert!(serde_json::from_value::<LogContentSchema>(content).is_err());
+    }
+
+    pub fn unknown_reason() -> impl Strategy<Value = ReasonSchema> {
+        "[a-z_]{1,24}"
+            .prop_map(|r#type| r#type.parse::<ReasonSchema>().unwrap())
+            .prop_filter("known reason", |reason| {
+                matches!(reason, ReasonSchema::Unknown(_))
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn reason_should_match_the_serialized_type(content in any::<LogContentSchema>()) {
+            let json = serde_json::to_value(&content).unwrap();
+            let r#type: ReasonSchema = serde_json::from_value(json["type"].clone()).unwrap();
+            prop_assert_eq!(r#type, content.reason());
+        }
+    }
+}
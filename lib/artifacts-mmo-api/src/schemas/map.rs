@@ -1,12 +1,13 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use super::MapContentSchema;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(test, derive(proptest_derive::Arbitrary))]
 pub struct MapSchema {
     pub name: String,
     pub skin: String,
-    pub x: String,
-    pub y: String,
+    pub x: i32,
+    pub y: i32,
     pub content: Option<MapContentSchema>,
 }
@@ -1,8 +1,8 @@
-use serde::Deserialize;
-use strum::Display;
+use strum::{Display, EnumString};
 
 /// SOURCE: <https://api.artifactsmmo.com/docs/#/operations/get_all_maps_maps__get>
-#[derive(Debug, Clone, Deserialize, Display)]
+#[derive(Debug, Clone, PartialEq, Eq, Display, EnumString)]
+#[cfg_attr(test, derive(proptest_derive::Arbitrary))]
 #[strum(serialize_all = "snake_case")]
 pub enum MapContentTypeSchema {
     Monster,
@@ -11,5 +11,8 @@ pub enum MapContentTypeSchema {
     Bank,
     GrandExchange,
     TasksMaster,
+    #[strum(default)]
     Unknown(String),
 }
+
+impl_serde_from_str!(MapContentTypeSchema);
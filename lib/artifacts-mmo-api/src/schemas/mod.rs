@@ -9,6 +9,25 @@ macro_rules! pub_mod_use {
     };
 }
 
+/// (De)serialize an enum through its strum `Display`/`EnumString` implementations so values
+/// unknown to this crate land in the `#[strum(default)]` variant instead of failing
+macro_rules! impl_serde_from_str {
+    ($name:ident) => {
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+                value.parse().map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+
 pub_mod_use! {
     token,
     response,
@@ -44,5 +63,190 @@ pub_mod_use! {
     character_fight_data,
     result,
     drop,
-    blocked_hits
+    blocked_hits,
+    skill_data,
+    skill_info,
+    bank_item,
+    gold_transaction,
+    ge_transaction,
+    ge_transaction_list,
+    task_type,
+    task,
+    task_data,
+    task_reward,
+    task_reward_data,
+    recycling_items,
+    recycling_data,
+    delete_item,
+    log_content,
+    log,
+    error_response
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Utc};
+    use proptest::prelude::*;
+    use serde::{de::DeserializeOwned, Serialize};
+
+    pub fn datetime() -> impl Strategy<Value = DateTime<Utc>> {
+        (0i64..=4_102_444_800, 0u32..1_000_000_000)
+            .prop_map(|(secs, nsecs)| DateTime::from_timestamp(secs, nsecs).unwrap())
+    }
+
+    pub fn json_value() -> impl Strategy<Value = serde_json::Value> {
+        prop_oneof![
+            Just(serde_json::Value::Null),
+            any::<u32>().prop_map(serde_json::Value::from),
+            ".*".prop_map(serde_json::Value::from),
+            prop::collection::btree_map("[a-z_]+", ".*", 0..4)
+                .prop_map(|map| serde_json::json!(map)),
+        ]
+    }
+
+    /// deserialize -> serialize -> deserialize, the JSON must be stable across the trip
+    fn assert_round_trip<T: Serialize + DeserializeOwned>(value: T) {
+        let json = serde_json::to_value(&value).unwrap();
+        let parsed: T = serde_json::from_value(json.clone()).unwrap();
+        let reserialized = serde_json::to_value(&parsed).unwrap();
+        let reparsed: T = serde_json::from_value(reserialized.clone()).unwrap();
+        assert_eq!(json, reserialized);
+        assert_eq!(reserialized, serde_json::to_value(reparsed).unwrap());
+    }
+
+    fn parse<T: DeserializeOwned>(value: &str) -> T {
+        serde_json::from_value(serde_json::Value::from(value)).unwrap()
+    }
+
+    #[test]
+    fn known_enum_values_should_match_the_api() {
+        assert_eq!(
+            parse::<super::CraftSkillSchema>("weaponcrafting"),
+            super::CraftSkillSchema::WeaponCrafting
+        );
+        assert_eq!(
+            parse::<super::ItemTypeSchema>("body_armor"),
+            super::ItemTypeSchema::BodyArmor
+        );
+        assert_eq!(
+            parse::<super::MapContentTypeSchema>("tasks_master"),
+            super::MapContentTypeSchema::TasksMaster
+        );
+        assert_eq!(
+            parse::<super::ReasonSchema>("buy_ge"),
+            super::ReasonSchema::BuyGe
+        );
+        assert_eq!(
+            parse::<super::SkillSchema>("fishing"),
+            super::SkillSchema::Fishing
+        );
+        assert_eq!(
+            parse::<super::SlotTypeSchema>("ring1"),
+            super::SlotTypeSchema::Ring1
+        );
+        assert_eq!(
+            parse::<super::TaskTypeSchema>("monsters"),
+            super::TaskTypeSchema::Monsters
+        );
+        assert_eq!(
+            serde_json::to_value(super::SlotTypeSchema::BodyArmor).unwrap(),
+            "body_armor"
+        );
+    }
+
+    #[test]
+    fn unseen_enum_values_should_round_trip_through_unknown() {
+        fn assert_unknown<T>(unknown: fn(String) -> T)
+        where
+            T: Serialize + DeserializeOwned + PartialEq + std::fmt::Debug,
+        {
+            let value = "added_in_next_season";
+            assert_eq!(parse::<T>(value), unknown(value.to_string()));
+            assert_eq!(
+                serde_json::to_value(unknown(value.to_string())).unwrap(),
+                value
+            );
+        }
+
+        assert_unknown(super::CraftSkillSchema::Unknown);
+        assert_unknown(super::ItemTypeSchema::Unknown);
+        assert_unknown(super::MapContentTypeSchema::Unknown);
+        assert_unknown(super::ReasonSchema::Unknown);
+        assert_unknown(super::SkillSchema::Unknown);
+        assert_unknown(super::SlotTypeSchema::Unknown);
+        assert_unknown(super::TaskTypeSchema::Unknown);
+    }
+
+    macro_rules! round_trip {
+        ($($name:ident: $schema:ty),+ $(,)?) => {
+            proptest! {
+                // Nested schemas like `CharacterSchema` are expensive to generate
+                #![proptest_config(ProptestConfig::with_cases(64))]
+                $(
+                #[test]
+                fn $name(value in any::<$schema>()) {
+                    assert_round_trip(value);
+                }
+                )+
+            }
+        };
+    }
+
+    round_trip! {
+        bearer_token_should_round_trip: super::BearerToken,
+        token_should_round_trip: super::TokenSchema,
+        response_should_round_trip: super::ResponseSchema<super::GoldSchema>,
+        paginated_response_should_round_trip: super::PaginatedResponseSchema<super::SimpleItemSchema>,
+        status_should_round_trip: super::StatusSchema,
+        announcement_should_round_trip: super::AnnouncementSchema,
+        ge_item_should_round_trip: super::GEItemSchema,
+        event_should_round_trip: super::EventSchema,
+        resource_should_round_trip: super::ResourceSchema,
+        skill_should_round_trip: super::SkillSchema,
+        drop_rate_should_round_trip: super::DropRateSchema,
+        monster_should_round_trip: super::MonsterSchema,
+        item_should_round_trip: super::ItemSchema,
+        single_item_should_round_trip: super::SingleItemSchema,
+        item_effect_should_round_trip: super::ItemEffectSchema,
+        craft_should_round_trip: super::CraftSchema,
+        craft_skill_should_round_trip: super::CraftSkillSchema,
+        simple_item_should_round_trip: super::SimpleItemSchema,
+        item_type_should_round_trip: super::ItemTypeSchema,
+        map_should_round_trip: super::MapSchema,
+        map_content_should_round_trip: super::MapContentSchema,
+        map_content_type_should_round_trip: super::MapContentTypeSchema,
+        character_should_round_trip: super::CharacterSchema,
+        inventory_slot_should_round_trip: super::InventorySlotSchema,
+        gold_should_round_trip: super::GoldSchema,
+        message_should_round_trip: super::MessageSchema,
+        character_movement_data_should_round_trip: super::CharacterMovementDataSchema,
+        cooldown_should_round_trip: super::CooldownSchema,
+        destination_response_should_round_trip: super::DestinationResponseSchema,
+        reason_should_round_trip: super::ReasonSchema,
+        slot_type_should_round_trip: super::SlotTypeSchema,
+        equip_request_should_round_trip: super::EquipRequestSchema,
+        fight_should_round_trip: super::FightSchema,
+        character_fight_data_should_round_trip: super::CharacterFightDataSchema,
+        result_should_round_trip: super::ResultSchema,
+        drop_should_round_trip: super::DropSchema,
+        blocked_hits_should_round_trip: super::BlockedHitsSchema,
+        skill_data_should_round_trip: super::SkillDataSchema,
+        skill_info_should_round_trip: super::SkillInfoSchema,
+        bank_item_should_round_trip: super::BankItemSchema,
+        gold_transaction_should_round_trip: super::GoldTransactionSchema,
+        ge_transaction_should_round_trip: super::GETransactionSchema,
+        ge_transaction_list_should_round_trip: super::GETransactionListSchema,
+        task_type_should_round_trip: super::TaskTypeSchema,
+        task_should_round_trip: super::TaskSchema,
+        task_data_should_round_trip: super::TaskDataSchema,
+        task_reward_should_round_trip: super::TaskRewardSchema,
+        task_reward_data_should_round_trip: super::TaskRewardDataSchema,
+        recycling_items_should_round_trip: super::RecyclingItemsSchema,
+        recycling_data_should_round_trip: super::RecyclingDataSchema,
+        delete_item_should_round_trip: super::DeleteItemSchema,
+        log_content_should_round_trip: super::LogContentSchema,
+        log_should_round_trip: super::LogSchema,
+        error_response_should_round_trip: super::ErrorResponseSchema,
+        error_should_round_trip: super::ErrorSchema,
+    }
 }
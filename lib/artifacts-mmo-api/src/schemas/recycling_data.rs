@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use super::{CharacterSchema, CooldownSchema, RecyclingItemsSchema};
+
+/// SOURCE: <https://api.artifactsmmo.com/docs/#/operations/action_recycling_my__name__action_recycling_post>
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(test, derive(proptest_derive::Arbitrary))]
+pub struct RecyclingDataSchema {
+    pub cooldown: CooldownSchema,
+    pub details: RecyclingItemsSchema,
+    pub character: CharacterSchema,
+}
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+use super::DropSchema;
+
+/// SOURCE: <https://api.artifactsmmo.com/docs/#/operations/action_recycling_my__name__action_recycling_post>
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(test, derive(proptest_derive::Arbitrary))]
+pub struct RecyclingItemsSchema {
+    pub items: Vec<DropSchema>,
+}
@@ -1,7 +1,9 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use strum::Display;
 
-#[derive(Debug, Clone, Deserialize, Display)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, Display)]
+#[cfg_attr(test, derive(proptest_derive::Arbitrary))]
+#[serde(rename_all = "lowercase")]
 #[strum(serialize_all = "lowercase")]
 pub enum ResultSchema {
     Win,
@@ -1,12 +1,15 @@
-use serde::Deserialize;
-use strum::Display;
+use strum::{Display, EnumString};
 
 /// SOURCE: <https://api.artifactsmmo.com/docs/#/operations/get_resources_resources__code__get>
-#[derive(Debug, Clone, Deserialize, Display)]
+#[derive(Debug, Clone, PartialEq, Eq, Display, EnumString)]
+#[cfg_attr(test, derive(proptest_derive::Arbitrary))]
 #[strum(serialize_all = "lowercase")]
 pub enum SkillSchema {
     Mining,
     Woodcutting,
     Fishing,
+    #[strum(default)]
     Unknown(String),
 }
+
+impl_serde_from_str!(SkillSchema);
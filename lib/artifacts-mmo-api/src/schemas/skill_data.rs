@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use super::{CharacterSchema, CooldownSchema, SkillInfoSchema};
+
+/// SOURCE: <https://api.artifactsmmo.com/docs/#/operations/action_gathering_my__name__action_gathering_post>
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(test, derive(proptest_derive::Arbitrary))]
+pub struct SkillDataSchema {
+    pub cooldown: CooldownSchema,
+    pub details: SkillInfoSchema,
+    pub character: CharacterSchema,
+}
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+use super::DropSchema;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(test, derive(proptest_derive::Arbitrary))]
+pub struct SkillInfoSchema {
+    pub xp: u32,
+    pub items: Vec<DropSchema>,
+}
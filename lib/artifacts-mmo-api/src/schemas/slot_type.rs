@@ -1,8 +1,9 @@
-use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
 
 /// SOURCE: <https://api.artifactsmmo.com/docs/#/operations/action_equip_item_my__name__action_equip_post>
-#[derive(Debug, Clone, Deserialize, Serialize)]
-#[serde(rename_all = "snake_case")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Display, EnumString)]
+#[cfg_attr(test, derive(proptest_derive::Arbitrary))]
+#[strum(serialize_all = "snake_case")]
 pub enum SlotTypeSchema {
     Weapon,
     Shield,
@@ -18,5 +19,8 @@ pub enum SlotTypeSchema {
     Artifact3,
     Consumable1,
     Consumable2,
+    #[strum(default)]
     Unknown(String),
 }
+
+impl_serde_from_str!(SlotTypeSchema);
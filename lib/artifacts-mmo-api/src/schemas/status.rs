@@ -1,9 +1,10 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use super::{AnnouncementSchema, ResponseSchema};
 
 /// SOURCE: <https://api.artifactsmmo.com/docs/#/operations/get_status__get>
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(test, derive(proptest_derive::Arbitrary))]
 pub struct StatusSchema {
     pub status: String,
     pub version: String,
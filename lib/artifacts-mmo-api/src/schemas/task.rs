@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use super::TaskTypeSchema;
+
+/// SOURCE: <https://api.artifactsmmo.com/docs/#/operations/action_accept_new_task_my__name__action_task_new_post>
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(test, derive(proptest_derive::Arbitrary))]
+pub struct TaskSchema {
+    pub code: String,
+    pub r#type: TaskTypeSchema,
+    pub total: u32,
+}
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use super::{CharacterSchema, CooldownSchema, TaskSchema};
+
+/// SOURCE: <https://api.artifactsmmo.com/docs/#/operations/action_accept_new_task_my__name__action_task_new_post>
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(test, derive(proptest_derive::Arbitrary))]
+pub struct TaskDataSchema {
+    pub cooldown: CooldownSchema,
+    pub task: TaskSchema,
+    pub character: CharacterSchema,
+}
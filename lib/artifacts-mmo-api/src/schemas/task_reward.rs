@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+/// SOURCE: <https://api.artifactsmmo.com/docs/#/operations/action_complete_task_my__name__action_task_complete_post>
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(test, derive(proptest_derive::Arbitrary))]
+pub struct TaskRewardSchema {
+    pub code: String,
+    pub quantity: u32,
+}
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use super::{CharacterSchema, CooldownSchema, TaskRewardSchema};
+
+/// SOURCE: <https://api.artifactsmmo.com/docs/#/operations/action_complete_task_my__name__action_task_complete_post>
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(test, derive(proptest_derive::Arbitrary))]
+pub struct TaskRewardDataSchema {
+    pub cooldown: CooldownSchema,
+    pub reward: TaskRewardSchema,
+    pub character: CharacterSchema,
+}
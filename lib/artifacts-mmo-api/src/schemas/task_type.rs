@@ -0,0 +1,15 @@
+use strum::{Display, EnumString};
+
+/// SOURCE: <https://api.artifactsmmo.com/docs/#/operations/action_accept_new_task_my__name__action_task_new_post>
+#[derive(Debug, Clone, PartialEq, Eq, Display, EnumString)]
+#[cfg_attr(test, derive(proptest_derive::Arbitrary))]
+#[strum(serialize_all = "lowercase")]
+pub enum TaskTypeSchema {
+    Monsters,
+    Resources,
+    Crafts,
+    #[strum(default)]
+    Unknown(String),
+}
+
+impl_serde_from_str!(TaskTypeSchema);
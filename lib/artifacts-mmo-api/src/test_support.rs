@@ -0,0 +1,486 @@
+//! Fixtures and helpers shared by the unit tests of several modules
+
+use http::{uri::PathAndQuery, Method};
+use serde_json::json;
+
+use crate::{
+    endpoints::*,
+    schemas::{
+        BankItemSchema, BearerToken, CharacterFightDataSchema, CharacterMovementDataSchema,
+        CharacterSchema, CraftSkillSchema, DeleteItemSchema, EquipRequestSchema, EventSchema,
+        GEItemSchema, GETransactionListSchema, GoldSchema, GoldTransactionSchema, ItemSchema,
+        ItemTypeSchema, LogSchema, MapContentTypeSchema, MapSchema, MessageSchema, MonsterSchema,
+        PaginatedResponseSchema, RecyclingDataSchema, ResourceSchema, ResponseSchema,
+        SimpleItemSchema, SingleItemSchema, SkillDataSchema, SkillSchema, SlotTypeSchema,
+        StatusSchema, TaskDataSchema, TaskRewardDataSchema, TokenSchema,
+    },
+    world::Position,
+    EncodedRequest, Error, ParseResponse,
+};
+
+/// Level 1 character without gear, `stats` overrides any of its fields
+pub fn character(hp: u32, stats: serde_json::Value) -> CharacterSchema {
+    let mut character: serde_json::Value = serde_json::from_str(
+        r#"{
+        "name": "tester", "skin": "men1", "level": 1, "xp": 0, "max_xp": 150, "total_xp": 0,
+        "gold": 0, "mining_level": 1, "mining_xp": 0, "mining_max_xp": 150, "attack_level": 1,
+        "woodcutting_level": 1, "woodcutting_xp": 0, "woodcutting_max_xp": 150,
+        "fishing_level": 1, "fishing_xp": 0, "fishing_max_xp": 150,
+        "weaponcrafting_level": 1, "weaponcrafting_xp": 0, "weaponcrafting_max_xp": 150,
+        "gearcrafting_level": 1, "gearcrafting_xp": 0, "gearcrafting_max_xp": 150,
+        "jewelrycrafting_level": 1, "jewelrycrafting_xp": 0, "jewelrycrafting_max_xp": 150,
+        "cooking_level": 1, "cooking_xp": 0, "cooking_max_xp": 150,
+        "hp": 0, "haste": 0,
+        "attack_fire": 0, "attack_earth": 0, "attack_water": 0, "attack_air": 0,
+        "dmg_fire": 0, "dmg_earth": 0, "dmg_water": 0, "dmg_air": 0,
+        "res_fire": 0, "res_earth": 0, "res_water": 0, "res_air": 0,
+        "x": 0, "y": 1, "cooldown": 0, "cooldown_expiration": "2024-08-01T00:00:00Z",
+        "weapon_slot": "", "shield_slot": "", "helmet_slot": "", "body_armor_slot": "",
+        "leg_armor_slot": "", "boots_slot": "", "ring1_slot": "", "ring2_slot": "",
+        "amulet_slot": "", "artifact1_slot": "", "artifact2_slot": "", "artifact3_slot": "",
+        "consumable1_slot": "", "consumable1_slot_quantity": 0,
+        "consumable2_slot": "", "consumable2_slot_quantity": 0,
+        "task": "", "task_type": "", "task_progress": 0, "task_total": 0,
+        "inventory_max_items": 100, "inventory": []
+    }"#,
+    )
+    .unwrap();
+    character["hp"] = hp.into();
+    character
+        .as_object_mut()
+        .unwrap()
+        .extend(stats.as_object().unwrap().clone());
+    serde_json::from_value(character).unwrap()
+}
+
+/// Level 1 monster without attacks, `stats` overrides any of its fields
+pub fn monster(hp: u32, stats: serde_json::Value) -> MonsterSchema {
+    let mut monster = json!({
+        "name": "Monster", "code": "monster", "level": 1, "hp": hp,
+        "attack_fire": 0, "attack_earth": 0, "attack_water": 0, "attack_air": 0,
+        "res_fire": 0, "res_earth": 0, "res_water": 0, "res_air": 0,
+        "min_gold": 0, "max_gold": 0, "drops": [],
+    });
+    monster
+        .as_object_mut()
+        .unwrap()
+        .extend(stats.as_object().unwrap().clone());
+    serde_json::from_value(monster).unwrap()
+}
+
+/// Inverse of `percent_encode`, to read back the queries in tests
+pub fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index] == b'%' {
+            let hex = std::str::from_utf8(&bytes[index + 1..index + 3]).unwrap();
+            decoded.push(u8::from_str_radix(hex, 16).unwrap());
+            index += 3;
+        } else {
+            decoded.push(bytes[index]);
+            index += 1;
+        }
+    }
+    String::from_utf8(decoded).unwrap()
+}
+
+/// Decoded parameters of the query of `path`, in order
+pub fn query_params(path: &http::uri::PathAndQuery) -> Vec<(String, String)> {
+    path.query()
+        .unwrap_or_default()
+        .split('&')
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap();
+            (percent_decode(key), percent_decode(value))
+        })
+        .collect()
+}
+
+/// A request built by one of the endpoints, stripped of its response type
+pub struct Endpoint {
+    pub name: &'static str,
+    pub method: Method,
+    pub path: PathAndQuery,
+    pub content: Vec<u8>,
+    /// Method and path with query the API expects for this request
+    pub expected: (Method, &'static str),
+}
+
+/// The response type of the endpoint is checked at compile time through `R`
+fn endpoint<T, R>(
+    name: &'static str,
+    request: Result<EncodedRequest<T>, Error>,
+    method: Method,
+    path: &'static str,
+) -> Endpoint
+where
+    EncodedRequest<T>: for<'de> ParseResponse<'de, Response = R>,
+{
+    let request = request.unwrap_or_else(|error| panic!("{name}: {error}"));
+    Endpoint {
+        name,
+        method: request.method,
+        path: request.path,
+        content: request.content,
+        expected: (method, path),
+    }
+}
+
+/// Every endpoint of the crate, with all of its optional filters set so that they show up in
+/// the query
+pub fn endpoints() -> Vec<Endpoint> {
+    let token = || BearerToken("token".to_string());
+    macro_rules! action {
+        ($function:ident, $request:ident, $response:ty, $path:literal $(, $field:ident($value:expr))*) => {
+            endpoint::<_, ResponseSchema<$response>>(
+                stringify!($function),
+                $function(
+                    $request::builder()
+                        .bearer_token(token())
+                        .name("Bob")
+                        $(.$field($value))*
+                        .build(),
+                ),
+                Method::POST,
+                $path,
+            )
+        };
+    }
+
+    vec![
+        endpoint::<_, ResponseSchema<StatusSchema>>(
+            "get_status",
+            Ok(get_status()),
+            Method::GET,
+            "/",
+        ),
+        // Accounts and token
+        endpoint::<_, MessageSchema>(
+            "create_account",
+            create_account(
+                CreateAccountRequest::builder()
+                    .username("bobby_1".to_string())
+                    .password("password".to_string())
+                    .email("bob@example.com".to_string())
+                    .build(),
+            ),
+            Method::POST,
+            "/accounts/create",
+        ),
+        endpoint::<_, TokenSchema>(
+            "generate_token",
+            generate_token(
+                GenerateTokenRequest::builder()
+                    .username("bobby_1")
+                    .password("password")
+                    .build(),
+            ),
+            Method::POST,
+            "/token/",
+        ),
+        endpoint::<_, MessageSchema>(
+            "change_password",
+            change_password(
+                ChangePasswordRequest::builder()
+                    .bearer_token(token())
+                    .password("password")
+                    .build(),
+            ),
+            Method::POST,
+            "/my/change_password",
+        ),
+        endpoint::<_, ResponseSchema<GoldSchema>>(
+            "get_bank_golds",
+            get_bank_golds(GetBankGoldsRequest::builder().bearer_token(token()).build()),
+            Method::GET,
+            "/my/bank/gold",
+        ),
+        endpoint::<_, PaginatedResponseSchema<SimpleItemSchema>>(
+            "get_bank_items",
+            get_bank_items(
+                GetBankItemsRequest::builder()
+                    .bearer_token(token())
+                    .item_code(Some("copper".to_string()))
+                    .build(),
+            ),
+            Method::GET,
+            "/my/bank/items/?page=1&size=50&item_code=copper",
+        ),
+        // Characters
+        endpoint::<_, ResponseSchema<CharacterSchema>>(
+            "create_character",
+            create_character(
+                CreateCharacterRequest::builder()
+                    .bearer_token(token())
+                    .name("Bob")
+                    .skin("men1")
+                    .build(),
+            ),
+            Method::POST,
+            "/characters/create",
+        ),
+        endpoint::<_, PaginatedResponseSchema<CharacterSchema>>(
+            "get_all_characters",
+            get_all_characters(
+                GetAllCharactersRequest::builder()
+                    .sort(Some(CraftSkillSchema::Mining))
+                    .build(),
+            ),
+            Method::GET,
+            "/characters/?page=1&size=50&sort=mining",
+        ),
+        endpoint::<_, ResponseSchema<CharacterSchema>>(
+            "get_character",
+            get_character(GetCharacterRequest::builder().name("Bob").build()),
+            Method::GET,
+            "/characters/Bob",
+        ),
+        endpoint::<_, ResponseSchema<Vec<CharacterSchema>>>(
+            "get_my_characters",
+            get_my_characters(
+                GetMyCharactersRequest::builder()
+                    .bearer_token(token())
+                    .build(),
+            ),
+            Method::GET,
+            "/my/characters",
+        ),
+        endpoint::<_, PaginatedResponseSchema<LogSchema>>(
+            "get_all_characters_logs",
+            get_all_characters_logs(
+                GetAllCharactersLogsRequest::builder()
+                    .bearer_token(token())
+                    .build(),
+            ),
+            Method::GET,
+            "/my/logs?page=1&size=50",
+        ),
+        // World data
+        endpoint::<_, PaginatedResponseSchema<EventSchema>>(
+            "get_all_events",
+            get_all_events(GetAllEventsRequest::builder().build()),
+            Method::GET,
+            "/events/?page=1&size=50",
+        ),
+        endpoint::<_, PaginatedResponseSchema<GEItemSchema>>(
+            "get_all_ge_items",
+            get_all_ge_items(GetAllGEItemsRequest::builder().build()),
+            Method::GET,
+            "/ge/?page=1&size=50",
+        ),
+        endpoint::<_, ResponseSchema<GEItemSchema>>(
+            "get_ge_item",
+            get_ge_item(
+                GetGEItemRequest::builder()
+                    .code("copper_ore".to_string())
+                    .build(),
+            ),
+            Method::GET,
+            "/ge/copper_ore",
+        ),
+        endpoint::<_, PaginatedResponseSchema<ItemSchema>>(
+            "get_all_items",
+            get_all_items(
+                GetAllItemsRequest::builder()
+                    .craft_material(Some("copper".to_string()))
+                    .craft_skill(Some(CraftSkillSchema::Mining))
+                    .name(Some("copper".to_string()))
+                    .r#type(Some(ItemTypeSchema::Resource))
+                    .max_level(Some(10))
+                    .min_level(Some(1))
+                    .build(),
+            ),
+            Method::GET,
+            "/items/?page=1&size=50&craft_material=copper&craft_skill=mining&name=copper\
+             &type=resource&max_level=10&min_level=1",
+        ),
+        endpoint::<_, ResponseSchema<SingleItemSchema>>(
+            "get_item",
+            get_item(GetItemRequest::builder().code("copper_ore").build()),
+            Method::GET,
+            "/items/copper_ore",
+        ),
+        endpoint::<_, PaginatedResponseSchema<MapSchema>>(
+            "get_all_maps",
+            get_all_maps(
+                GetAllMapsRequest::builder()
+                    .content_code(Some("chicken".to_string()))
+                    .content_type(Some(MapContentTypeSchema::Monster))
+                    .build(),
+            ),
+            Method::GET,
+            "/maps/?page=1&size=50&content_code=chicken&content_type=monster",
+        ),
+        endpoint::<_, ResponseSchema<MapSchema>>(
+            "get_map",
+            get_map(GetMapRequest::from(Position::new(-1, 2))),
+            Method::GET,
+            "/maps/-1/2",
+        ),
+        endpoint::<_, PaginatedResponseSchema<MonsterSchema>>(
+            "get_all_monsters",
+            get_all_monsters(
+                GetAllMonstersRequest::builder()
+                    .drop(Some("feather".to_string()))
+                    .max_level(Some(10))
+                    .min_level(Some(1))
+                    .build(),
+            ),
+            Method::GET,
+            "/monsters/?page=1&size=50&drop=feather&max_level=10&min_level=1",
+        ),
+        endpoint::<_, ResponseSchema<MonsterSchema>>(
+            "get_monster",
+            get_monster(GetMonsterRequest::builder().code("chicken").build()),
+            Method::GET,
+            "/monsters/chicken",
+        ),
+        endpoint::<_, PaginatedResponseSchema<ResourceSchema>>(
+            "get_all_resources",
+            get_all_resources(
+                GetAllResourcesRequest::builder()
+                    .drop(Some("ash_wood".to_string()))
+                    .max_level(Some(10))
+                    .min_level(Some(1))
+                    .skill(Some(SkillSchema::Woodcutting))
+                    .build(),
+            ),
+            Method::GET,
+            "/resources/?page=1&size=50&drop=ash_wood&max_level=10&min_level=1&skill=woodcutting",
+        ),
+        endpoint::<_, ResponseSchema<ResourceSchema>>(
+            "get_resource",
+            get_resource(GetResourceRequest::builder().code("ash_tree").build()),
+            Method::GET,
+            "/resources/ash_tree",
+        ),
+        // Actions
+        action!(
+            action_move,
+            ActionMoveRequest,
+            CharacterMovementDataSchema,
+            "/my/Bob/action/move",
+            x(1),
+            y(-2)
+        ),
+        action!(
+            action_fight,
+            ActionFightRequest,
+            CharacterFightDataSchema,
+            "/my/Bob/action/fight"
+        ),
+        action!(
+            action_gathering,
+            ActionGatheringRequest,
+            SkillDataSchema,
+            "/my/Bob/action/gathering"
+        ),
+        action!(
+            action_crafting,
+            ActionCraftingRequest,
+            SkillDataSchema,
+            "/my/Bob/action/crafting",
+            code("copper"),
+            quantity(1)
+        ),
+        action!(
+            action_equip_item,
+            ActionEquipItemRequest,
+            EquipRequestSchema,
+            "/my/Bob/action/equip",
+            code("copper_dagger"),
+            slot(SlotTypeSchema::Weapon)
+        ),
+        action!(
+            action_unequip_item,
+            ActionUnequipItemRequest,
+            EquipRequestSchema,
+            "/my/Bob/action/unequip",
+            slot(SlotTypeSchema::Weapon)
+        ),
+        action!(
+            action_deposit_bank,
+            ActionDepositBankRequest,
+            BankItemSchema,
+            "/my/Bob/action/bank/deposit",
+            code("copper"),
+            quantity(1)
+        ),
+        action!(
+            action_withdraw_bank,
+            ActionWithdrawBankRequest,
+            BankItemSchema,
+            "/my/Bob/action/bank/withdraw",
+            code("copper"),
+            quantity(1)
+        ),
+        action!(
+            action_deposit_bank_gold,
+            ActionDepositBankGoldRequest,
+            GoldTransactionSchema,
+            "/my/Bob/action/bank/deposit/gold",
+            quantity(1)
+        ),
+        action!(
+            action_withdraw_bank_gold,
+            ActionWithdrawBankGoldRequest,
+            GoldTransactionSchema,
+            "/my/Bob/action/bank/withdraw/gold",
+            quantity(1)
+        ),
+        action!(
+            action_recycling,
+            ActionRecyclingRequest,
+            RecyclingDataSchema,
+            "/my/Bob/action/recycling",
+            code("copper_dagger"),
+            quantity(1)
+        ),
+        action!(
+            action_delete_item,
+            ActionDeleteItemRequest,
+            DeleteItemSchema,
+            "/my/Bob/action/delete",
+            code("copper"),
+            quantity(1)
+        ),
+        action!(
+            action_ge_buy_item,
+            ActionGEBuyItemRequest,
+            GETransactionListSchema,
+            "/my/Bob/action/ge/buy",
+            code("copper"),
+            quantity(1),
+            price(10)
+        ),
+        action!(
+            action_ge_sell_item,
+            ActionGESellItemRequest,
+            GETransactionListSchema,
+            "/my/Bob/action/ge/sell",
+            code("copper"),
+            quantity(1),
+            price(10)
+        ),
+        action!(
+            action_accept_new_task,
+            ActionAcceptNewTaskRequest,
+            TaskDataSchema,
+            "/my/Bob/action/task/new"
+        ),
+        action!(
+            action_complete_task,
+            ActionCompleteTaskRequest,
+            TaskRewardDataSchema,
+            "/my/Bob/action/task/complete"
+        ),
+        action!(
+            action_task_exchange,
+            ActionTaskExchangeRequest,
+            TaskRewardDataSchema,
+            "/my/Bob/action/task/exchange"
+        ),
+    ]
+}
@@ -0,0 +1,213 @@
+//! Typed model of the world map built from `get_all_maps` results
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::schemas::{
+    CharacterSchema, DestinationResponseSchema, MapContentSchema, MapContentTypeSchema, MapSchema,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Position {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Position {
+    pub fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    /// `u64` because the distance between the extreme coordinates doesn't fit in a `u32`
+    pub fn manhattan_distance(&self, other: &Position) -> u64 {
+        u64::from(self.x.abs_diff(other.x)) + u64::from(self.y.abs_diff(other.y))
+    }
+}
+
+impl From<&MapSchema> for Position {
+    fn from(map: &MapSchema) -> Self {
+        Self::new(map.x, map.y)
+    }
+}
+
+impl From<&CharacterSchema> for Position {
+    fn from(character: &CharacterSchema) -> Self {
+        Self::new(character.x, character.y)
+    }
+}
+
+impl From<&DestinationResponseSchema> for Position {
+    fn from(destination: &DestinationResponseSchema) -> Self {
+        Self::new(destination.x, destination.y)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct World {
+    tiles: HashMap<Position, MapSchema>,
+}
+
+impl World {
+    pub fn new(maps: impl IntoIterator<Item = MapSchema>) -> Self {
+        let mut world = Self::default();
+        world.extend(maps);
+        world
+    }
+
+    pub fn extend(&mut self, maps: impl IntoIterator<Item = MapSchema>) {
+        self.tiles
+            .extend(maps.into_iter().map(|map| (Position::from(&map), map)));
+    }
+
+    pub fn tile(&self, position: Position) -> Option<&MapSchema> {
+        self.tiles.get(&position)
+    }
+
+    pub fn tiles(&self) -> impl Iterator<Item = &MapSchema> {
+        self.tiles.values()
+    }
+
+    /// Moving is done in straight lines on the grid, the cost is the number of tiles crossed
+    pub fn travel_cost(&self, from: Position, to: Position) -> u64 {
+        from.manhattan_distance(&to)
+    }
+
+    /// Closest tile whose content matches `predicate`, ties are broken by position
+    pub fn nearest(
+        &self,
+        from: Position,
+        predicate: impl Fn(&MapContentSchema) -> bool,
+    ) -> Option<&MapSchema> {
+        self.tiles
+            .iter()
+            .filter(|(_, map)| map.content.as_ref().is_some_and(&predicate))
+            .min_by_key(|(position, _)| (from.manhattan_distance(position), **position))
+            .map(|(_, map)| map)
+    }
+
+    pub fn nearest_of_type(
+        &self,
+        from: Position,
+        content_type: &MapContentTypeSchema,
+    ) -> Option<&MapSchema> {
+        self.nearest(from, |content| &content.r#type == content_type)
+    }
+
+    /// e.g. the closest `Workshop` with code `weaponcrafting` or `Monster` with code `chicken`
+    pub fn nearest_content(
+        &self,
+        from: Position,
+        content_type: &MapContentTypeSchema,
+        code: &str,
+    ) -> Option<&MapSchema> {
+        self.nearest(from, |content| {
+            &content.r#type == content_type && content.code == code
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::{Position, World};
+    use crate::schemas::{MapContentSchema, MapContentTypeSchema, MapSchema};
+
+    fn tile(x: i32, y: i32, content: Option<(MapContentTypeSchema, &str)>) -> MapSchema {
+        MapSchema {
+            name: format!("{x},{y}"),
+            skin: String::new(),
+            x,
+            y,
+            content: content.map(|(r#type, code)| MapContentSchema {
+                r#type,
+                code: code.to_string(),
+            }),
+        }
+    }
+
+    fn world() -> World {
+        World::new([
+            tile(0, 0, None),
+            tile(4, 1, Some((MapContentTypeSchema::Bank, "bank"))),
+            tile(-2, -1, Some((MapContentTypeSchema::Bank, "bank"))),
+            tile(2, 0, Some((MapContentTypeSchema::Workshop, "cooking"))),
+            tile(
+                -1,
+                3,
+                Some((MapContentTypeSchema::Workshop, "weaponcrafting")),
+            ),
+            tile(0, 1, Some((MapContentTypeSchema::Monster, "chicken"))),
+        ])
+    }
+
+    #[test]
+    fn manhattan_distance_should_handle_negative_coordinates() {
+        assert_eq!(
+            Position::new(-2, -1).manhattan_distance(&Position::new(4, 1)),
+            8
+        );
+        assert_eq!(
+            world().travel_cost(Position::new(0, 0), Position::new(-1, 3)),
+            4
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn manhattan_distance_should_not_panic_with_valid_input(
+            x1 in any::<i32>(),
+            y1 in any::<i32>(),
+            x2 in any::<i32>(),
+            y2 in any::<i32>(),
+        ) {
+            let from = Position::new(x1, y1);
+            let to = Position::new(x2, y2);
+            prop_assert_eq!(from.manhattan_distance(&to), to.manhattan_distance(&from));
+        }
+    }
+
+    #[test]
+    fn manhattan_distance_should_not_overflow() {
+        assert_eq!(
+            Position::new(i32::MIN, i32::MIN)
+                .manhattan_distance(&Position::new(i32::MAX, i32::MAX)),
+            2 * u64::from(u32::MAX)
+        );
+    }
+
+    #[test]
+    fn nearest_of_type_should_find_closest_tile() {
+        let world = world();
+        let bank = world
+            .nearest_of_type(Position::new(0, 0), &MapContentTypeSchema::Bank)
+            .unwrap();
+        assert_eq!(Position::from(bank), Position::new(-2, -1));
+    }
+
+    #[test]
+    fn nearest_content_should_match_code() {
+        let world = world();
+        let workshop = world
+            .nearest_content(
+                Position::new(0, 0),
+                &MapContentTypeSchema::Workshop,
+                "weaponcrafting",
+            )
+            .unwrap();
+        assert_eq!(Position::from(workshop), Position::new(-1, 3));
+        assert!(world
+            .nearest_content(Position::new(0, 0), &MapContentTypeSchema::Monster, "cow")
+            .is_none());
+    }
+
+    #[test]
+    fn map_schema_should_parse_negative_coordinates() {
+        let map: MapSchema = serde_json::from_str(
+            r#"{"name":"Forest","skin":"forest_1","x":-1,"y":-2,"content":null}"#,
+        )
+        .unwrap();
+        assert_eq!(Position::from(&map), Position::new(-1, -2));
+    }
+}
@@ -0,0 +1,248 @@
+use std::sync::{Arc, Mutex};
+
+use artifacts_mmo_api::{
+    cassette::Cassette,
+    endpoints::{
+        GetAllItemsRequest, GetAllMapsRequest, GetAllMonstersRequest, GetAllResourcesRequest,
+    },
+    game_data::GameData,
+    pagination::{PaginatedRequest, Paginator},
+    schemas::{BearerToken, PaginatedResponseSchema},
+    EncodedRequest, ParseResponse, API_BASE_URL,
+};
+use futures::{stream, Stream, TryStreamExt};
+use typed_builder::TypedBuilder;
+
+use crate::{into_http_request, lock, Error};
+
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct Client {
+    #[builder(default = API_BASE_URL.to_string(), setter(into))]
+    base_url: String,
+    #[builder(default, setter(strip_option))]
+    bearer_token: Option<BearerToken>,
+    #[builder(default)]
+    http: reqwest::Client,
+    /// Record every interaction, or answer from the cassette without sending anything when it
+    /// was loaded to be replayed
+    #[builder(default, setter(strip_option))]
+    cassette: Option<Arc<Mutex<Cassette>>>,
+}
+
+impl Client {
+    /// Send the request and parse the response, error status codes are mapped to
+    /// `artifacts_mmo_api::Error` through `ParseResponse::parse_http_response`
+    #[tracing::instrument(level = "trace", skip_all, fields(path = %request.path))]
+    pub async fn send<T, R>(&self, request: EncodedRequest<T>) -> Result<R, Error>
+    where
+        EncodedRequest<T>: for<'de> ParseResponse<'de, Response = R>,
+    {
+        let recorded = match &self.cassette {
+            Some(cassette) => {
+                let mut cassette = lock(cassette);
+                if cassette.is_replaying() {
+                    return Ok(cassette.respond(&request)?);
+                }
+                Some(request.clone())
+            }
+            None => None,
+        };
+        let request = into_http_request(&self.base_url, self.bearer_token.as_ref(), request)?;
+        let response = self
+            .http
+            .execute(reqwest::Request::try_from(request)?)
+            .await?;
+        let status = response.status();
+        let body = response.bytes().await?;
+
+        if let (Some(cassette), Some(request)) = (&self.cassette, recorded) {
+            lock(cassette).record(&request, status, &body);
+        }
+
+        Ok(<EncodedRequest<T> as ParseResponse>::parse_http_response(
+            status, &body,
+        )?)
+    }
+
+    /// Stream the items of every page, starting from the page set on `request`
+    pub fn paginate<R, T>(&self, request: R) -> impl Stream<Item = Result<T, Error>> + '_
+    where
+        R: PaginatedRequest + 'static,
+        T: 'static,
+        EncodedRequest<R>: for<'de> ParseResponse<'de, Response = PaginatedResponseSchema<T>>,
+    {
+        stream::try_unfold(Paginator::new(request), move |mut paginator| async move {
+            let Some(request) = paginator.next_request() else {
+                return Ok::<_, Error>(None);
+            };
+            let response = self.send(request?).await?;
+            paginator.advance(&response);
+
+            Ok(Some((
+                stream::iter(response.data.into_iter().map(Ok)),
+                paginator,
+            )))
+        })
+        .try_flatten()
+    }
+
+    /// Fill `game_data` with every item, monster, resource and map, walking the list endpoints
+    /// with the largest page size
+    pub async fn fill_game_data(&self, game_data: &mut GameData) -> Result<(), Error> {
+        game_data.extend_items(
+            self.paginate(GetAllItemsRequest::builder().size(100).build())
+                .try_collect::<Vec<_>>()
+                .await?,
+        );
+        game_data.extend_monsters(
+            self.paginate(GetAllMonstersRequest::builder().size(100).build())
+                .try_collect::<Vec<_>>()
+                .await?,
+        );
+        game_data.extend_resources(
+            self.paginate(GetAllResourcesRequest::builder().size(100).build())
+                .try_collect::<Vec<_>>()
+                .await?,
+        );
+        game_data.extend_maps(
+            self.paginate(GetAllMapsRequest::builder().size(100).build())
+                .try_collect::<Vec<_>>()
+                .await?,
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use artifacts_mmo_api::{
+        cassette::Cassette,
+        endpoints::{action_move, get_status, ActionMoveRequest, GetAllGEItemsRequest},
+        schemas::BearerToken,
+    };
+    use futures::TryStreamExt;
+    use wiremock::{
+        matchers::{body_json, header, method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use super::Client;
+
+    #[tokio::test]
+    async fn send_should_parse_successful_response() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(
+                r#"{"data":{"status":"online","version":"1.3","characters_online":1,"announcements":[],"last_wipe":"","next_wipe":""}}"#,
+                "application/json",
+            ))
+            .mount(&server)
+            .await;
+
+        let client = Client::builder().base_url(server.uri()).build();
+        let response = client.send(get_status()).await.unwrap();
+        assert_eq!(response.data.status, "online");
+    }
+
+    #[tokio::test]
+    async fn send_should_map_error_status() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/my/Bob/action/move"))
+            .and(header("authorization", "Bearer token"))
+            .and(body_json(serde_json::json!({ "x": 1, "y": 2 })))
+            .respond_with(ResponseTemplate::new(499).set_body_raw(
+                r#"{"error":{"code":499,"message":"Character in cooldown."}}"#,
+                "application/json",
+            ))
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .base_url(server.uri())
+            .bearer_token(BearerToken("token".to_string()))
+            .build();
+        let request = action_move(
+            ActionMoveRequest::builder()
+                .bearer_token(BearerToken("token".to_string()))
+                .name("Bob")
+                .x(1)
+                .y(2)
+                .build(),
+        )
+        .unwrap();
+
+        assert!(matches!(
+            client.send(request).await,
+            Err(crate::Error::Api(
+                artifacts_mmo_api::Error::CharacterInCooldown(_)
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn paginate_should_stream_every_page() {
+        let server = MockServer::start().await;
+        for page in 1..=2 {
+            Mock::given(method("GET"))
+                .and(path("/ge/"))
+                .and(query_param("page", page.to_string()))
+                .respond_with(ResponseTemplate::new(200).set_body_raw(
+                    format!(
+                        r#"{{"data":[{{"code":"item{page}","stock":1,"sell_price":1,"buy_price":2}}],"total":2,"page":{page},"size":1,"pages":2}}"#
+                    ),
+                    "application/json",
+                ))
+                .mount(&server)
+                .await;
+        }
+
+        let client = Client::builder().base_url(server.uri()).build();
+        let items = client
+            .paginate(GetAllGEItemsRequest::builder().size(1).build())
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            items.into_iter().map(|item| item.code).collect::<Vec<_>>(),
+            ["item1", "item2"]
+        );
+    }
+
+    #[tokio::test]
+    async fn cassette_should_replay_recorded_responses() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(
+                r#"{"data":{"status":"online","version":"1.3","characters_online":1,"announcements":[],"last_wipe":"","next_wipe":""}}"#,
+                "application/json",
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let recording = Arc::new(Mutex::new(Cassette::new()));
+        let client = Client::builder()
+            .base_url(server.uri())
+            .cassette(recording.clone())
+            .build();
+        client.send(get_status()).await.unwrap();
+        let mut saved = Vec::new();
+        recording.lock().unwrap().save(&mut saved).unwrap();
+
+        // Nothing listens on this address, the response can only come from the cassette
+        let client = Client::builder()
+            .base_url("http://127.0.0.1:1")
+            .cassette(Arc::new(Mutex::new(
+                Cassette::load(saved.as_slice()).unwrap(),
+            )))
+            .build();
+        let response = client.send(get_status()).await.unwrap();
+        assert_eq!(response.data.version, "1.3");
+    }
+}
@@ -0,0 +1,212 @@
+use std::sync::{Arc, Mutex};
+
+use artifacts_mmo_api::{
+    cassette::Cassette,
+    endpoints::{
+        GetAllItemsRequest, GetAllMapsRequest, GetAllMonstersRequest, GetAllResourcesRequest,
+    },
+    game_data::GameData,
+    pagination::{PaginatedRequest, Paginator},
+    schemas::{BearerToken, PaginatedResponseSchema},
+    EncodedRequest, ParseResponse, API_BASE_URL,
+};
+use typed_builder::TypedBuilder;
+
+use crate::{into_http_request, lock, Error};
+
+#[derive(Debug, Clone, TypedBuilder)]
+pub struct BlockingClient {
+    #[builder(default = API_BASE_URL.to_string(), setter(into))]
+    base_url: String,
+    #[builder(default, setter(strip_option))]
+    bearer_token: Option<BearerToken>,
+    #[builder(default)]
+    http: reqwest::blocking::Client,
+    /// Record every interaction, or answer from the cassette without sending anything when it
+    /// was loaded to be replayed
+    #[builder(default, setter(strip_option))]
+    cassette: Option<Arc<Mutex<Cassette>>>,
+}
+
+impl BlockingClient {
+    /// Send the request and parse the response, error status codes are mapped to
+    /// `artifacts_mmo_api::Error` through `ParseResponse::parse_http_response`
+    #[tracing::instrument(level = "trace", skip_all, fields(path = %request.path))]
+    pub fn send<T, R>(&self, request: EncodedRequest<T>) -> Result<R, Error>
+    where
+        EncodedRequest<T>: for<'de> ParseResponse<'de, Response = R>,
+    {
+        let recorded = match &self.cassette {
+            Some(cassette) => {
+                let mut cassette = lock(cassette);
+                if cassette.is_replaying() {
+                    return Ok(cassette.respond(&request)?);
+                }
+                Some(request.clone())
+            }
+            None => None,
+        };
+        let request = into_http_request(&self.base_url, self.bearer_token.as_ref(), request)?;
+        let response = self
+            .http
+            .execute(reqwest::blocking::Request::try_from(request)?)?;
+        let status = response.status();
+        let body = response.bytes()?;
+
+        if let (Some(cassette), Some(request)) = (&self.cassette, recorded) {
+            lock(cassette).record(&request, status, &body);
+        }
+
+        Ok(<EncodedRequest<T> as ParseResponse>::parse_http_response(
+            status, &body,
+        )?)
+    }
+
+    /// Iterate over the items of every page, starting from the page set on `request`
+    pub fn paginate<R, T>(&self, request: R) -> impl Iterator<Item = Result<T, Error>> + '_
+    where
+        R: PaginatedRequest + 'static,
+        T: 'static,
+        EncodedRequest<R>: for<'de> ParseResponse<'de, Response = PaginatedResponseSchema<T>>,
+    {
+        let mut paginator = Paginator::new(request);
+        let mut items = Vec::new().into_iter();
+        let mut failed = false;
+
+        std::iter::from_fn(move || loop {
+            if let Some(item) = items.next() {
+                return Some(Ok(item));
+            }
+            if failed {
+                return None;
+            }
+            match paginator
+                .next_request()?
+                .map_err(Error::from)
+                .and_then(|request| self.send(request))
+            {
+                Ok(response) => {
+                    paginator.advance(&response);
+                    items = response.data.into_iter();
+                }
+                Err(error) => {
+                    failed = true;
+                    return Some(Err(error));
+                }
+            }
+        })
+    }
+
+    /// Fill `game_data` with every item, monster, resource and map, walking the list endpoints
+    /// with the largest page size
+    pub fn fill_game_data(&self, game_data: &mut GameData) -> Result<(), Error> {
+        game_data.extend_items(
+            self.paginate(GetAllItemsRequest::builder().size(100).build())
+                .collect::<Result<Vec<_>, _>>()?,
+        );
+        game_data.extend_monsters(
+            self.paginate(GetAllMonstersRequest::builder().size(100).build())
+                .collect::<Result<Vec<_>, _>>()?,
+        );
+        game_data.extend_resources(
+            self.paginate(GetAllResourcesRequest::builder().size(100).build())
+                .collect::<Result<Vec<_>, _>>()?,
+        );
+        game_data.extend_maps(
+            self.paginate(GetAllMapsRequest::builder().size(100).build())
+                .collect::<Result<Vec<_>, _>>()?,
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use artifacts_mmo_api::{
+        endpoints::{get_bank_golds, GetBankGoldsRequest, GetBankItemsRequest},
+        schemas::BearerToken,
+    };
+    use wiremock::{
+        matchers::{header, method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use super::BlockingClient;
+
+    #[tokio::test]
+    async fn send_should_parse_successful_response() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/my/bank/gold"))
+            .and(header("authorization", "Bearer token"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_raw(r#"{"data":{"quantity":42}}"#, "application/json"),
+            )
+            .mount(&server)
+            .await;
+
+        let base_url = server.uri();
+        // The blocking client must not run inside the async runtime
+        let response = tokio::task::spawn_blocking(move || {
+            let client = BlockingClient::builder().base_url(base_url).build();
+            let request = get_bank_golds(
+                GetBankGoldsRequest::builder()
+                    .bearer_token(BearerToken("token".to_string()))
+                    .build(),
+            )
+            .unwrap();
+            client.send(request)
+        })
+        .await
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(response.data.quantity, 42);
+    }
+
+    #[tokio::test]
+    async fn paginate_should_iterate_every_page() {
+        let server = MockServer::start().await;
+        for page in 1..=2 {
+            Mock::given(method("GET"))
+                .and(path("/my/bank/items/"))
+                .and(query_param("page", page.to_string()))
+                .respond_with(ResponseTemplate::new(200).set_body_raw(
+                    format!(
+                        r#"{{"data":[{{"code":"item{page}","quantity":{page}}}],"total":2,"page":{page},"size":1,"pages":2}}"#
+                    ),
+                    "application/json",
+                ))
+                .mount(&server)
+                .await;
+        }
+
+        let base_url = server.uri();
+        let items = tokio::task::spawn_blocking(move || {
+            let client = BlockingClient::builder()
+                .base_url(base_url)
+                .bearer_token(BearerToken("token".to_string()))
+                .build();
+            client
+                .paginate(
+                    GetBankItemsRequest::builder()
+                        .bearer_token(BearerToken("token".to_string()))
+                        .size(1)
+                        .build(),
+                )
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .await
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(
+            items
+                .into_iter()
+                .map(|item| item.quantity)
+                .collect::<Vec<_>>(),
+            [1, 2]
+        );
+    }
+}
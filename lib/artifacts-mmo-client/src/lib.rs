@@ -0,0 +1,73 @@
+//! Clients sending the requests built by `artifacts-mmo-api` to the API
+#[cfg(feature = "async")]
+mod r#async;
+#[cfg(feature = "blocking")]
+mod blocking;
+
+#[cfg(any(feature = "async", feature = "blocking"))]
+use std::{
+    str::FromStr,
+    sync::{Mutex, MutexGuard},
+};
+
+#[cfg(any(feature = "async", feature = "blocking"))]
+use artifacts_mmo_api::{cassette::Cassette, schemas::BearerToken, EncodedRequest};
+#[cfg(any(feature = "async", feature = "blocking"))]
+use http::{header::AUTHORIZATION, HeaderValue, Request, Uri};
+use thiserror::Error;
+
+#[cfg(feature = "blocking")]
+pub use self::blocking::BlockingClient;
+#[cfg(feature = "async")]
+pub use self::r#async::Client;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error(transparent)]
+    Api(#[from] artifacts_mmo_api::Error),
+    #[error("Failed to build the request: {0}")]
+    Request(#[from] http::Error),
+    #[error("Failed to parse URI: {0}")]
+    InvalidUri(#[from] http::uri::InvalidUri),
+    #[error("Failed to parse header value: {0}")]
+    InvalidHeaderValue(#[from] http::header::InvalidHeaderValue),
+    #[cfg(any(feature = "async", feature = "blocking"))]
+    #[error("HTTP client error: {0}")]
+    Http(#[from] reqwest::Error),
+}
+
+/// Turn an encoded request into a complete HTTP request against `base_url`, the bearer token is
+/// only added when the request doesn't carry its own authorization
+#[cfg(any(feature = "async", feature = "blocking"))]
+fn into_http_request<T>(
+    base_url: &str,
+    bearer_token: Option<&BearerToken>,
+    request: EncodedRequest<T>,
+) -> Result<Request<Vec<u8>>, Error> {
+    let uri = Uri::from_str(&format!(
+        "{}{}",
+        base_url.trim_end_matches('/'),
+        request.path
+    ))?;
+    let mut request = Request::<Vec<u8>>::try_from(request)?;
+    *request.uri_mut() = uri;
+
+    if let Some(bearer_token) = bearer_token {
+        if !request.headers().contains_key(AUTHORIZATION) {
+            request.headers_mut().insert(
+                AUTHORIZATION,
+                HeaderValue::from_str(&format!("Bearer {}", bearer_token.0))?,
+            );
+        }
+    }
+
+    Ok(request)
+}
+
+/// A panic while the cassette was locked doesn't make the recorded interactions invalid
+#[cfg(any(feature = "async", feature = "blocking"))]
+fn lock(cassette: &Mutex<Cassette>) -> MutexGuard<'_, Cassette> {
+    cassette
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
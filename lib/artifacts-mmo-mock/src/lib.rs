@@ -0,0 +1,1069 @@
+//! In-process fake of the Artifacts API, to test requests, responses and clients offline
+//!
+//! The fake keeps the world, the characters, the bank, the Grand Exchange and the logs in memory
+//! and serves the same paths as the real API. Actions start cooldowns and fail with the documented
+//! status codes, rewards are deterministic so tests can assert on them.
+mod routes;
+
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use artifacts_mmo_api::{
+    game_data::GameData,
+    schemas::{
+        CharacterSchema, GEItemSchema, InventorySlotSchema, ItemSchema, LogSchema, MapSchema,
+        MonsterSchema, ResourceSchema, SimpleItemSchema,
+    },
+};
+use chrono::{DateTime, TimeDelta, Utc};
+use http::{Request, Response};
+use wiremock::{matchers::any, Mock, MockServer, Respond, ResponseTemplate};
+
+/// Characters an account can have
+pub const MAX_CHARACTERS: usize = 5;
+/// Slots in the inventory of a new character
+pub const INVENTORY_SLOTS: u32 = 20;
+/// Account owning the characters, as written in the logs
+pub const ACCOUNT: &str = "fake";
+
+#[derive(Debug, Clone, Default)]
+pub struct FakeApi {
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    token: String,
+    offset: TimeDelta,
+    characters: Vec<CharacterSchema>,
+    bank: Vec<SimpleItemSchema>,
+    bank_gold: u32,
+    ge: Vec<GEItemSchema>,
+    logs: Vec<LogSchema>,
+    items: Vec<ItemSchema>,
+    monsters: Vec<MonsterSchema>,
+    resources: Vec<ResourceSchema>,
+    maps: Vec<MapSchema>,
+}
+
+impl State {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now() + self.offset
+    }
+}
+
+impl FakeApi {
+    /// Fake of an account authenticated by `token`
+    pub fn new(token: impl Into<String>) -> Self {
+        let api = Self::default();
+        api.state().token = token.into();
+        api
+    }
+
+    pub fn with_game_data(self, game_data: &GameData) -> Self {
+        self.with_items(game_data.items().cloned())
+            .with_monsters(game_data.monsters().cloned())
+            .with_resources(game_data.resources().cloned())
+            .with_maps(game_data.maps().iter().cloned())
+    }
+
+    pub fn with_items(self, items: impl IntoIterator<Item = ItemSchema>) -> Self {
+        self.state().items.extend(items);
+        self
+    }
+
+    pub fn with_monsters(self, monsters: impl IntoIterator<Item = MonsterSchema>) -> Self {
+        self.state().monsters.extend(monsters);
+        self
+    }
+
+    pub fn with_resources(self, resources: impl IntoIterator<Item = ResourceSchema>) -> Self {
+        self.state().resources.extend(resources);
+        self
+    }
+
+    pub fn with_maps(self, maps: impl IntoIterator<Item = MapSchema>) -> Self {
+        self.state().maps.extend(maps);
+        self
+    }
+
+    pub fn with_character(self, character: CharacterSchema) -> Self {
+        self.state().characters.push(character);
+        self
+    }
+
+    pub fn with_bank(self, items: impl IntoIterator<Item = SimpleItemSchema>) -> Self {
+        self.state().bank.extend(items);
+        self
+    }
+
+    pub fn with_bank_gold(self, quantity: u32) -> Self {
+        self.state().bank_gold = quantity;
+        self
+    }
+
+    /// Listings of the Grand Exchange, trades are done at their prices and move their stock
+    pub fn with_ge_items(self, items: impl IntoIterator<Item = GEItemSchema>) -> Self {
+        self.state().ge.extend(items);
+        self
+    }
+
+    /// Move the clock of the fake forward, to get past cooldowns without sleeping
+    pub fn advance(&self, by: TimeDelta) {
+        self.state().offset += by;
+    }
+
+    pub fn now(&self) -> DateTime<Utc> {
+        self.state().now()
+    }
+
+    pub fn character(&self, name: &str) -> Option<CharacterSchema> {
+        self.state()
+            .characters
+            .iter()
+            .find(|character| character.name == name)
+            .cloned()
+    }
+
+    pub fn bank(&self) -> Vec<SimpleItemSchema> {
+        self.state().bank.clone()
+    }
+
+    pub fn bank_gold(&self) -> u32 {
+        self.state().bank_gold
+    }
+
+    pub fn ge_item(&self, code: &str) -> Option<GEItemSchema> {
+        self.state()
+            .ge
+            .iter()
+            .find(|item| item.code == code)
+            .cloned()
+    }
+
+    /// Logs of every action, oldest first
+    pub fn logs(&self) -> Vec<LogSchema> {
+        self.state().logs.clone()
+    }
+
+    /// Answer a request the way the API would, the URI only needs a path and a query
+    pub fn handle(&self, request: &Request<Vec<u8>>) -> Response<Vec<u8>> {
+        routes::handle(&mut self.state(), request)
+    }
+
+    /// Serve the fake over HTTP, point a client at `MockServer::uri`
+    pub async fn start(&self) -> MockServer {
+        let server = MockServer::start().await;
+        Mock::given(any())
+            .respond_with(Responder(self.clone()))
+            .mount(&server)
+            .await;
+        server
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        // A panic in a test must not hide the state from the other tests
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+struct Responder(FakeApi);
+
+impl Respond for Responder {
+    fn respond(&self, request: &wiremock::Request) -> ResponseTemplate {
+        let path = match request.url.query() {
+            Some(query) => format!("{}?{query}", request.url.path()),
+            None => request.url.path().to_string(),
+        };
+        let mut builder = Request::builder().method(request.method.clone()).uri(path);
+        for (key, value) in &request.headers {
+            builder = builder.header(key, value);
+        }
+        let response = match builder.body(request.body.clone()) {
+            Ok(request) => self.0.handle(&request),
+            Err(_) => return ResponseTemplate::new(400),
+        };
+
+        ResponseTemplate::new(response.status().as_u16())
+            .set_body_raw(response.into_body(), "application/json")
+    }
+}
+
+/// Character as returned by the API right after its creation
+pub fn new_character(name: &str, skin: &str, now: DateTime<Utc>) -> CharacterSchema {
+    CharacterSchema {
+        name: name.to_string(),
+        skin: skin.to_string(),
+        level: 1,
+        xp: 0,
+        max_xp: 150,
+        total_xp: 0,
+        gold: 0,
+        mining_level: 1,
+        mining_xp: 0,
+        mining_max_xp: 150,
+        attack_level: 1,
+        woodcutting_level: 1,
+        woodcutting_xp: 0,
+        woodcutting_max_xp: 150,
+        fishing_level: 1,
+        fishing_xp: 0,
+        fishing_max_xp: 150,
+        weaponcrafting_level: 1,
+        weaponcrafting_xp: 0,
+        weaponcrafting_max_xp: 150,
+        gearcrafting_level: 1,
+        gearcrafting_xp: 0,
+        gearcrafting_max_xp: 150,
+        jewelrycrafting_level: 1,
+        jewelrycrafting_xp: 0,
+        jewelrycrafting_max_xp: 150,
+        cooking_level: 1,
+        cooking_xp: 0,
+        cooking_max_xp: 150,
+        hp: 120,
+        haste: 0,
+        attack_fire: 0,
+        attack_earth: 0,
+        attack_water: 0,
+        attack_air: 0,
+        dmg_fire: 0,
+        dmg_earth: 0,
+        dmg_water: 0,
+        dmg_air: 0,
+        res_fire: 0,
+        res_earth: 0,
+        res_water: 0,
+        res_air: 0,
+        x: 0,
+        y: 0,
+        cooldown: 0,
+        cooldown_expiration: now,
+        weapon_slot: String::new(),
+        shield_slot: String::new(),
+        helmet_slot: String::new(),
+        body_armor_slot: String::new(),
+        leg_armor_slot: String::new(),
+        boots_slot: String::new(),
+        ring1_slot: String::new(),
+        ring2_slot: String::new(),
+        amulet_slot: String::new(),
+        artifact1_slot: String::new(),
+        artifact2_slot: String::new(),
+        artifact3_slot: String::new(),
+        consumable1_slot: String::new(),
+        consumable1_slot_quantity: 0,
+        consumable2_slot: String::new(),
+        consumable2_slot_quantity: 0,
+        task: String::new(),
+        task_type: None,
+        task_progress: 0,
+        task_total: 0,
+        inventory_max_items: 100,
+        inventory: (1..=INVENTORY_SLOTS)
+            .map(|slot| InventorySlotSchema {
+                slot,
+                code: String::new(),
+                quantity: 0,
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use artifacts_mmo_api::{
+        endpoints::{
+            action_accept_new_task, action_complete_task, action_crafting, action_delete_item,
+            action_deposit_bank, action_deposit_bank_gold, action_equip_item, action_fight,
+            action_ge_buy_item, action_ge_sell_item, action_move, action_recycling,
+            action_task_exchange, action_unequip_item, action_withdraw_bank,
+            action_withdraw_bank_gold, create_character, get_all_characters_logs, get_all_items,
+            get_all_maps, get_all_monsters, get_bank_items, get_map, get_my_characters,
+            ActionAcceptNewTaskRequest, ActionCompleteTaskRequest, ActionCraftingRequest,
+            ActionDeleteItemRequest, ActionDepositBankGoldRequest, ActionDepositBankRequest,
+            ActionEquipItemRequest, ActionFightRequest, ActionGEBuyItemRequest,
+            ActionGESellItemRequest, ActionMoveRequest, ActionRecyclingRequest,
+            ActionTaskExchangeRequest, ActionUnequipItemRequest, ActionWithdrawBankGoldRequest,
+            ActionWithdrawBankRequest, CreateCharacterRequest, GetAllCharactersLogsRequest,
+            GetAllItemsRequest, GetAllMapsRequest, GetAllMonstersRequest, GetBankItemsRequest,
+            GetMapRequest, GetMyCharactersRequest,
+        },
+        game_data::GameData,
+        schemas::{
+            BankItemSchema, BearerToken, CharacterSchema, CraftSchema, CraftSkillSchema,
+            GEItemSchema, ItemEffectSchema, ItemSchema, ItemTypeSchema, LogContentSchema,
+            MapContentSchema, MapContentTypeSchema, MapSchema, MonsterSchema, ResultSchema,
+            SimpleItemSchema, SlotTypeSchema, TaskTypeSchema,
+        },
+        EncodedRequest, Error, ParseResponse,
+    };
+    use artifacts_mmo_client::Client;
+    use chrono::TimeDelta;
+    use futures::TryStreamExt;
+    use http::Request;
+
+    use super::{new_character, FakeApi};
+
+    const TOKEN: &str = "token";
+
+    fn token() -> BearerToken {
+        BearerToken(TOKEN.to_string())
+    }
+
+    fn send<T, R>(api: &FakeApi, request: Result<EncodedRequest<T>, Error>) -> Result<R, Error>
+    where
+        EncodedRequest<T>: for<'de> ParseResponse<'de, Response = R>,
+    {
+        let request = Request::try_from(request.unwrap()).unwrap();
+        let response = api.handle(&request);
+        <EncodedRequest<T> as ParseResponse>::parse_http_response(
+            response.status(),
+            response.body(),
+        )
+    }
+
+    fn map(x: i32, y: i32, content: Option<(MapContentTypeSchema, &str)>) -> MapSchema {
+        MapSchema {
+            name: format!("{x},{y}"),
+            skin: String::new(),
+            x,
+            y,
+            content: content.map(|(r#type, code)| MapContentSchema {
+                r#type,
+                code: code.to_string(),
+            }),
+        }
+    }
+
+    fn api() -> FakeApi {
+        FakeApi::new(TOKEN)
+            .with_maps([
+                map(0, 0, None),
+                map(1, 0, Some((MapContentTypeSchema::Monster, "chicken"))),
+                map(-1, 2, Some((MapContentTypeSchema::Bank, "bank"))),
+            ])
+            .with_monsters([MonsterSchema {
+                name: "Chicken".to_string(),
+                code: "chicken".to_string(),
+                level: 1,
+                hp: 20,
+                attack_fire: 0,
+                attack_earth: 0,
+                attack_water: 4,
+                attack_air: 0,
+                res_fire: 0,
+                res_earth: 0,
+                res_water: 0,
+                res_air: 0,
+                min_gold: 3,
+                max_gold: 5,
+                drops: Vec::new(),
+            }])
+            .with_items([ItemSchema {
+                name: "Wooden stick".to_string(),
+                code: "wooden_stick".to_string(),
+                level: 1,
+                r#type: ItemTypeSchema::Weapon,
+                subtype: String::new(),
+                description: String::new(),
+                effects: vec![ItemEffectSchema {
+                    name: "attack_earth".to_string(),
+                    value: 4,
+                }],
+                craft: None,
+            }])
+            .with_bank([SimpleItemSchema {
+                code: "wooden_stick".to_string(),
+                quantity: 1,
+            }])
+    }
+
+    fn create(api: &FakeApi, name: &str) -> CharacterSchema {
+        send(
+            api,
+            create_character(
+                CreateCharacterRequest::builder()
+                    .bearer_token(token())
+                    .name(name)
+                    .skin("men1")
+                    .build(),
+            ),
+        )
+        .unwrap()
+        .data
+    }
+
+    fn move_to(api: &FakeApi, name: &str, x: i32, y: i32) -> Result<CharacterSchema, Error> {
+        send(
+            api,
+            action_move(
+                ActionMoveRequest::builder()
+                    .bearer_token(token())
+                    .name(name)
+                    .x(x)
+                    .y(y)
+                    .build(),
+            ),
+        )
+        .map(|response| response.data.character)
+    }
+
+    fn withdraw(
+        api: &FakeApi,
+        name: &str,
+        code: &str,
+        quantity: u32,
+    ) -> Result<BankItemSchema, Error> {
+        send(
+            api,
+            action_withdraw_bank(
+                ActionWithdrawBankRequest::builder()
+                    .bearer_token(token())
+                    .name(name)
+                    .code(code)
+                    .quantity(quantity)
+                    .build(),
+            ),
+        )
+        .map(|response| response.data)
+    }
+
+    fn last_log(api: &FakeApi) -> LogContentSchema {
+        let logs = send(
+            api,
+            get_all_characters_logs(
+                GetAllCharactersLogsRequest::builder()
+                    .bearer_token(token())
+                    .build(),
+            ),
+        )
+        .unwrap();
+        assert_eq!(logs.total as usize, api.logs().len());
+        logs.data.last().unwrap().content.clone()
+    }
+
+    #[test]
+    fn actions_should_enforce_cooldowns() {
+        let api = api();
+        create(&api, "Bob");
+
+        let character = move_to(&api, "Bob", -1, 2).unwrap();
+        assert_eq!((character.x, character.y), (-1, 2));
+        assert_eq!(character.cooldown, 15);
+        assert!(matches!(
+            move_to(&api, "Bob", 0, 0),
+            Err(Error::CharacterInCooldown(_))
+        ));
+
+        api.advance(TimeDelta::seconds(15));
+        assert!(matches!(
+            move_to(&api, "Bob", -1, 2),
+            Err(Error::AlreadyAtDestination(_))
+        ));
+        assert!(matches!(
+            move_to(&api, "Bob", 5, 5),
+            Err(Error::NotFound(_))
+        ));
+        assert!(matches!(
+            move_to(&api, "Alice", 0, 0),
+            Err(Error::CharacterNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn fight_should_need_a_monster_on_the_map() {
+        let api = api();
+        create(&api, "Bob");
+        let fight = || {
+            send(
+                &api,
+                action_fight(
+                    ActionFightRequest::builder()
+                        .bearer_token(token())
+                        .name("Bob")
+                        .build(),
+                ),
+            )
+        };
+
+        assert!(matches!(fight(), Err(Error::ContentNotFoundOnMap(_))));
+        move_to(&api, "Bob", 1, 0).unwrap();
+        api.advance(TimeDelta::seconds(5));
+        // A new character has no attack, the chicken takes 4 of its 120 HP every other turn
+        let fight = fight().unwrap().data;
+        assert_eq!(fight.fight.result, ResultSchema::Loss);
+        assert_eq!(fight.fight.turns, 60);
+    }
+
+    #[test]
+    fn bank_and_equipment_should_update_state() {
+        let api = api();
+        create(&api, "Bob");
+        move_to(&api, "Bob", -1, 2).unwrap();
+        api.advance(TimeDelta::minutes(1));
+
+        let equip = || {
+            send(
+                &api,
+                action_equip_item(
+                    ActionEquipItemRequest::builder()
+                        .bearer_token(token())
+                        .name("Bob")
+                        .code("wooden_stick")
+                        .slot(SlotTypeSchema::Weapon)
+                        .build(),
+                ),
+            )
+        };
+        assert!(matches!(equip(), Err(Error::MissingItem(_))));
+
+        let withdraw = withdraw(&api, "Bob", "wooden_stick", 1).unwrap();
+        assert!(withdraw.bank.is_empty());
+        assert_eq!(withdraw.character.inventory_count("wooden_stick"), 1);
+
+        api.advance(TimeDelta::minutes(1));
+        let equipped = equip().unwrap().data.character;
+        assert_eq!(equipped.weapon_slot, "wooden_stick");
+        assert_eq!(equipped.attack_earth, 4);
+
+        api.advance(TimeDelta::minutes(1));
+        assert!(matches!(equip(), Err(Error::MissingItem(_))));
+        let unequipped = send(
+            &api,
+            action_unequip_item(
+                ActionUnequipItemRequest::builder()
+                    .bearer_token(token())
+                    .name("Bob")
+                    .slot(SlotTypeSchema::Weapon)
+                    .build(),
+            ),
+        )
+        .unwrap()
+        .data
+        .character;
+        assert_eq!(unequipped.attack_earth, 0);
+
+        api.advance(TimeDelta::minutes(1));
+        send(
+            &api,
+            action_deposit_bank(
+                ActionDepositBankRequest::builder()
+                    .bearer_token(token())
+                    .name("Bob")
+                    .code("wooden_stick")
+                    .quantity(1)
+                    .build(),
+            ),
+        )
+        .unwrap();
+        assert_eq!(api.bank()[0].quantity, 1);
+        assert_eq!(
+            api.character("Bob")
+                .unwrap()
+                .inventory_count("wooden_stick"),
+            0
+        );
+    }
+
+    #[test]
+    fn maps_should_be_served_by_coordinates() {
+        let api = api();
+        let map = send(&api, get_map(GetMapRequest::builder().x(-1).y(2).build()))
+            .unwrap()
+            .data;
+        assert_eq!(map.content.unwrap().code, "bank");
+
+        let maps = send(&api, get_all_maps(GetAllMapsRequest::builder().build())).unwrap();
+        assert_eq!(maps.total, 3);
+        assert!(matches!(
+            send(&api, get_map(GetMapRequest::builder().x(9).y(9).build())),
+            Err(Error::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn list_endpoints_should_apply_filters() {
+        let api = api();
+        let items = |request: GetAllItemsRequest| send(&api, get_all_items(request)).unwrap();
+        assert_eq!(
+            items(
+                GetAllItemsRequest::builder()
+                    .r#type(Some(ItemTypeSchema::Weapon))
+                    .name(Some("Stick".to_string()))
+                    .build()
+            )
+            .total,
+            1
+        );
+        assert_eq!(
+            items(
+                GetAllItemsRequest::builder()
+                    .r#type(Some(ItemTypeSchema::Resource))
+                    .build()
+            )
+            .total,
+            0
+        );
+        assert_eq!(
+            items(GetAllItemsRequest::builder().min_level(Some(2)).build()).total,
+            0
+        );
+
+        let maps = send(
+            &api,
+            get_all_maps(
+                GetAllMapsRequest::builder()
+                    .content_type(Some(MapContentTypeSchema::Monster))
+                    .build(),
+            ),
+        )
+        .unwrap();
+        assert_eq!(maps.data[0].name, "1,0");
+        assert_eq!(maps.total, 1);
+
+        let monsters = send(
+            &api,
+            get_all_monsters(
+                GetAllMonstersRequest::builder()
+                    .drop(Some("feather".to_string()))
+                    .build(),
+            ),
+        )
+        .unwrap();
+        assert_eq!(monsters.total, 0);
+
+        let bank = send(
+            &api,
+            get_bank_items(
+                GetBankItemsRequest::builder()
+                    .bearer_token(token())
+                    .item_code(Some("copper".to_string()))
+                    .build(),
+            ),
+        )
+        .unwrap();
+        assert_eq!(bank.total, 0);
+    }
+
+    #[test]
+    fn pages_past_the_end_should_be_empty() {
+        let items = send(
+            &api(),
+            get_all_items(
+                GetAllItemsRequest::builder()
+                    .page(u32::MAX)
+                    .size(100)
+                    .build(),
+            ),
+        )
+        .unwrap();
+        assert!(items.data.is_empty());
+        assert_eq!(items.total, 1);
+    }
+
+    #[test]
+    fn authenticated_endpoints_should_reject_other_tokens() {
+        let api = api();
+        let response = send(
+            &api,
+            get_my_characters(
+                GetMyCharactersRequest::builder()
+                    .bearer_token(BearerToken("wrong".to_string()))
+                    .build(),
+            ),
+        );
+        assert!(matches!(
+            response,
+            Err(Error::UnexpectedStatus { status, .. }) if status == 401
+        ));
+    }
+
+    #[test]
+    fn crafting_and_recycling_should_need_the_workshop() {
+        let api = api()
+            .with_maps([map(
+                2,
+                0,
+                Some((MapContentTypeSchema::Workshop, "weaponcrafting")),
+            )])
+            .with_items([
+                ItemSchema {
+                    name: "Ash plank".to_string(),
+                    code: "ash_plank".to_string(),
+                    level: 1,
+                    r#type: ItemTypeSchema::Resource,
+                    subtype: String::new(),
+                    description: String::new(),
+                    effects: Vec::new(),
+                    craft: None,
+                },
+                ItemSchema {
+                    name: "Wooden staff".to_string(),
+                    code: "wooden_staff".to_string(),
+                    level: 1,
+                    r#type: ItemTypeSchema::Weapon,
+                    subtype: String::new(),
+                    description: String::new(),
+                    effects: Vec::new(),
+                    craft: Some(CraftSchema {
+                        skill: CraftSkillSchema::WeaponCrafting,
+                        level: 1,
+                        items: vec![SimpleItemSchema {
+                            code: "ash_plank".to_string(),
+                            quantity: 2,
+                        }],
+                        quantity: 1,
+                    }),
+                },
+            ])
+            .with_bank([SimpleItemSchema {
+                code: "ash_plank".to_string(),
+                quantity: 4,
+            }]);
+        create(&api, "Bob");
+        move_to(&api, "Bob", -1, 2).unwrap();
+        api.advance(TimeDelta::minutes(1));
+        withdraw(&api, "Bob", "ash_plank", 4).unwrap();
+        api.advance(TimeDelta::minutes(1));
+
+        let craft = |quantity| {
+            send(
+                &api,
+                action_crafting(
+                    ActionCraftingRequest::builder()
+                        .bearer_token(token())
+                        .name("Bob")
+                        .code("wooden_staff")
+                        .quantity(quantity)
+                        .build(),
+                ),
+            )
+        };
+        let recycle = |code: &str| {
+            send(
+                &api,
+                action_recycling(
+                    ActionRecyclingRequest::builder()
+                        .bearer_token(token())
+                        .name("Bob")
+                        .code(code)
+                        .quantity(1)
+                        .build(),
+                ),
+            )
+        };
+        assert!(matches!(craft(1), Err(Error::ContentNotFoundOnMap(_))));
+        move_to(&api, "Bob", 2, 0).unwrap();
+        api.advance(TimeDelta::minutes(1));
+        assert!(matches!(craft(3), Err(Error::MissingItem(_))));
+
+        let crafted = craft(2).unwrap().data;
+        assert_eq!(crafted.cooldown.total_seconds, 10);
+        assert_eq!(crafted.character.inventory_count("wooden_staff"), 2);
+        assert_eq!(crafted.character.inventory_count("ash_plank"), 0);
+        assert!(matches!(last_log(&api), LogContentSchema::Crafting { .. }));
+
+        api.advance(TimeDelta::minutes(1));
+        assert!(matches!(
+            recycle("ash_plank"),
+            Err(Error::ItemCannotBeRecycled(_))
+        ));
+        let recycled = recycle("wooden_staff").unwrap().data;
+        assert_eq!(recycled.character.inventory_count("wooden_staff"), 1);
+        assert_eq!(recycled.character.inventory_count("ash_plank"), 1);
+        assert!(matches!(last_log(&api), LogContentSchema::Recycling { .. }));
+    }
+
+    #[test]
+    fn gold_and_ge_should_move_stock_and_gold() {
+        let api = api()
+            .with_maps([map(
+                3,
+                0,
+                Some((MapContentTypeSchema::GrandExchange, "grand_exchange")),
+            )])
+            .with_ge_items([GEItemSchema {
+                code: "wooden_stick".to_string(),
+                stock: 5,
+                sell_price: 8,
+                buy_price: 10,
+            }])
+            .with_bank_gold(100);
+        create(&api, "Bob");
+        move_to(&api, "Bob", -1, 2).unwrap();
+        api.advance(TimeDelta::minutes(1));
+
+        let withdraw_gold = |quantity| {
+            send(
+                &api,
+                action_withdraw_bank_gold(
+                    ActionWithdrawBankGoldRequest::builder()
+                        .bearer_token(token())
+                        .name("Bob")
+                        .quantity(quantity)
+                        .build(),
+                ),
+            )
+        };
+        let deposit_gold = |quantity| {
+            send(
+                &api,
+                action_deposit_bank_gold(
+                    ActionDepositBankGoldRequest::builder()
+                        .bearer_token(token())
+                        .name("Bob")
+                        .quantity(quantity)
+                        .build(),
+                ),
+            )
+        };
+        assert!(matches!(
+            withdraw_gold(150),
+            Err(Error::BankInsufficientGold(_))
+        ));
+        let withdrawn = withdraw_gold(100).unwrap().data;
+        assert_eq!(withdrawn.bank.quantity, 0);
+        assert_eq!(withdrawn.character.gold, 100);
+
+        api.advance(TimeDelta::minutes(1));
+        move_to(&api, "Bob", 3, 0).unwrap();
+        api.advance(TimeDelta::minutes(1));
+        assert!(matches!(
+            deposit_gold(1),
+            Err(Error::ContentNotFoundOnMap(_))
+        ));
+
+        let buy = |quantity, price| {
+            send(
+                &api,
+                action_ge_buy_item(
+                    ActionGEBuyItemRequest::builder()
+                        .bearer_token(token())
+                        .name("Bob")
+                        .code("wooden_stick")
+                        .quantity(quantity)
+                        .price(price)
+                        .build(),
+                ),
+            )
+        };
+        assert!(matches!(buy(1, 9), Err(Error::GEPriceChanged(_))));
+        assert!(matches!(buy(6, 10), Err(Error::GENoStock(_))));
+        let bought = buy(2, 10).unwrap().data;
+        assert_eq!(bought.transaction.total_price, 20);
+        assert_eq!(bought.character.gold, 80);
+        assert_eq!(bought.character.inventory_count("wooden_stick"), 2);
+        assert_eq!(api.ge_item("wooden_stick").unwrap().stock, 3);
+        assert!(matches!(last_log(&api), LogContentSchema::BuyGe { .. }));
+
+        api.advance(TimeDelta::minutes(1));
+        let sold = send(
+            &api,
+            action_ge_sell_item(
+                ActionGESellItemRequest::builder()
+                    .bearer_token(token())
+                    .name("Bob")
+                    .code("wooden_stick")
+                    .quantity(1)
+                    .price(8)
+                    .build(),
+            ),
+        )
+        .unwrap()
+        .data;
+        assert_eq!(sold.character.gold, 88);
+        assert_eq!(api.ge_item("wooden_stick").unwrap().stock, 4);
+        assert!(matches!(last_log(&api), LogContentSchema::SellGe { .. }));
+
+        api.advance(TimeDelta::minutes(1));
+        move_to(&api, "Bob", -1, 2).unwrap();
+        api.advance(TimeDelta::minutes(1));
+        assert!(matches!(deposit_gold(89), Err(Error::InsufficientGold(_))));
+        deposit_gold(88).unwrap();
+        assert_eq!(api.bank_gold(), 88);
+    }
+
+    #[test]
+    fn tasks_should_count_fights_and_reward_coins() {
+        let mut character = new_character("Bob", "men1", chrono::Utc::now());
+        character.attack_earth = 100;
+        character.inventory[0].code = "tasks_coin".to_string();
+        character.inventory[0].quantity = 2;
+        let api = api()
+            .with_maps([map(
+                0,
+                1,
+                Some((MapContentTypeSchema::TasksMaster, "monsters")),
+            )])
+            .with_character(character);
+        move_to(&api, "Bob", 0, 1).unwrap();
+        api.advance(TimeDelta::minutes(1));
+
+        let accept = || {
+            send(
+                &api,
+                action_accept_new_task(
+                    ActionAcceptNewTaskRequest::builder()
+                        .bearer_token(token())
+                        .name("Bob")
+                        .build(),
+                ),
+            )
+        };
+        let complete = || {
+            send(
+                &api,
+                action_complete_task(
+                    ActionCompleteTaskRequest::builder()
+                        .bearer_token(token())
+                        .name("Bob")
+                        .build(),
+                ),
+            )
+        };
+        assert!(matches!(complete(), Err(Error::NoTask(_))));
+        let task = accept().unwrap().data;
+        assert_eq!(task.task.code, "chicken");
+        assert_eq!(task.task.r#type, TaskTypeSchema::Monsters);
+        assert_eq!(task.character.task_total, 10);
+        assert!(matches!(last_log(&api), LogContentSchema::Task { .. }));
+
+        api.advance(TimeDelta::minutes(1));
+        assert!(matches!(accept(), Err(Error::TaskAlreadyAssigned(_))));
+        assert!(matches!(complete(), Err(Error::TaskNotCompleted(_))));
+
+        move_to(&api, "Bob", 1, 0).unwrap();
+        for _ in 0..10 {
+            api.advance(TimeDelta::minutes(1));
+            let fight = send(
+                &api,
+                action_fight(
+                    ActionFightRequest::builder()
+                        .bearer_token(token())
+                        .name("Bob")
+                        .build(),
+                ),
+            )
+            .unwrap()
+            .data;
+            assert_eq!(fight.fight.result, ResultSchema::Win);
+        }
+        assert_eq!(api.character("Bob").unwrap().task_progress, 10);
+
+        api.advance(TimeDelta::minutes(1));
+        move_to(&api, "Bob", 0, 1).unwrap();
+        api.advance(TimeDelta::minutes(1));
+        let reward = complete().unwrap().data;
+        assert_eq!(reward.reward.code, "tasks_coin");
+        assert!(reward.character.task.is_empty());
+        assert_eq!(reward.character.inventory_count("tasks_coin"), 3);
+
+        api.advance(TimeDelta::minutes(1));
+        let exchange = || {
+            send(
+                &api,
+                action_task_exchange(
+                    ActionTaskExchangeRequest::builder()
+                        .bearer_token(token())
+                        .name("Bob")
+                        .build(),
+                ),
+            )
+        };
+        let exchanged = exchange().unwrap().data;
+        assert_eq!(exchanged.reward.code, "wooden_stick");
+        assert_eq!(exchanged.character.inventory_count("tasks_coin"), 0);
+
+        api.advance(TimeDelta::minutes(1));
+        assert!(matches!(exchange(), Err(Error::MissingItem(_))));
+    }
+
+    #[test]
+    fn full_inventory_should_reject_items_without_changing_state() {
+        let mut character = new_character("Bob", "men1", chrono::Utc::now());
+        character.inventory_max_items = 2;
+        character.inventory[0].code = "wooden_stick".to_string();
+        character.inventory[0].quantity = 2;
+        let api = api().with_character(character);
+        move_to(&api, "Bob", -1, 2).unwrap();
+        api.advance(TimeDelta::minutes(1));
+
+        assert!(matches!(
+            withdraw(&api, "Bob", "wooden_stick", 1),
+            Err(Error::InventoryFull(_))
+        ));
+        assert_eq!(api.bank()[0].quantity, 1);
+
+        let delete = |quantity| {
+            send(
+                &api,
+                action_delete_item(
+                    ActionDeleteItemRequest::builder()
+                        .bearer_token(token())
+                        .name("Bob")
+                        .code("wooden_stick")
+                        .quantity(quantity)
+                        .build(),
+                ),
+            )
+        };
+        assert!(matches!(delete(3), Err(Error::MissingItem(_))));
+        let deleted = delete(2).unwrap().data;
+        assert_eq!(deleted.character.inventory_count("wooden_stick"), 0);
+        assert!(matches!(
+            last_log(&api),
+            LogContentSchema::DeleteItem { .. }
+        ));
+
+        api.advance(TimeDelta::minutes(1));
+        let withdrawn = withdraw(&api, "Bob", "wooden_stick", 1).unwrap();
+        assert_eq!(withdrawn.character.inventory_count("wooden_stick"), 1);
+    }
+
+    #[tokio::test]
+    async fn client_should_talk_to_the_fake_over_http() {
+        let api = api();
+        create(&api, "Bob");
+        let server = api.start().await;
+        let client = Client::builder()
+            .base_url(server.uri())
+            .bearer_token(token())
+            .build();
+
+        let characters = client
+            .send(
+                get_my_characters(
+                    GetMyCharactersRequest::builder()
+                        .bearer_token(token())
+                        .build(),
+                )
+                .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(characters.data[0].name, "Bob");
+
+        let items = client
+            .paginate(GetAllItemsRequest::builder().size(1).build())
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        assert_eq!(items[0].code, "wooden_stick");
+    }
+
+    #[tokio::test]
+    async fn client_should_fill_game_data_from_the_fake() {
+        let server = api().start().await;
+        let client = Client::builder().base_url(server.uri()).build();
+
+        let mut game_data = GameData::default();
+        client.fill_game_data(&mut game_data).await.unwrap();
+
+        assert!(game_data.item("wooden_stick").is_some());
+        assert!(game_data.monster("chicken").is_some());
+        assert_eq!(game_data.resources().count(), 0);
+        assert_eq!(game_data.maps().len(), 3);
+    }
+}
@@ -0,0 +1,1333 @@
+//! Handlers of the fake, one per endpoint
+
+use std::{ops::RangeInclusive, str::FromStr};
+
+use artifacts_mmo_api::{
+    character::SkillProgress,
+    combat,
+    gear::fits,
+    schemas::{
+        BankItemSchema, CharacterFightDataSchema, CharacterMovementDataSchema, CharacterSchema,
+        CooldownSchema, CraftSkillSchema, DeleteItemSchema, DestinationResponseSchema,
+        DropRateSchema, DropSchema, EquipRequestSchema, ErrorResponseSchema, ErrorSchema,
+        FightSchema, GEItemSchema, GETransactionListSchema, GETransactionSchema, GoldSchema,
+        GoldTransactionSchema, ItemSchema, ItemTypeSchema, LogContentSchema, LogSchema,
+        MapContentTypeSchema, MapSchema, MonsterSchema, PaginatedResponseSchema, ReasonSchema,
+        RecyclingDataSchema, RecyclingItemsSchema, ResourceSchema, ResponseSchema, ResultSchema,
+        SimpleItemSchema, SingleItemSchema, SkillDataSchema, SkillInfoSchema, SkillSchema,
+        SlotTypeSchema, StatusSchema, TaskDataSchema, TaskRewardDataSchema, TaskRewardSchema,
+        TaskSchema, TaskTypeSchema,
+    },
+    world::Position,
+    API_VERSION,
+};
+use chrono::{DateTime, TimeDelta, Utc};
+use http::{header::AUTHORIZATION, header::CONTENT_TYPE, Method, Request, Response};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{new_character, State, ACCOUNT, MAX_CHARACTERS};
+
+/// Cooldown of a move, per tile crossed
+const MOVE_SECONDS_PER_TILE: u32 = 5;
+const GATHERING_SECONDS: u32 = 25;
+/// Cooldown of crafting and recycling, per item
+const CRAFTING_SECONDS: u32 = 5;
+/// Cooldown of equipping, unequipping, deleting, bank, Grand Exchange and task transactions
+const INVENTORY_SECONDS: u32 = 3;
+/// Monsters to kill for a task
+const TASK_TOTAL: u32 = 10;
+/// Given when completing a task, spent to exchange tasks
+const TASKS_COIN: &str = "tasks_coin";
+const TASK_EXCHANGE_COINS: u32 = 3;
+
+struct ApiError(u16, String);
+
+impl ApiError {
+    fn new(status: u16, message: impl Into<String>) -> Self {
+        Self(status, message.into())
+    }
+}
+
+type Reply = Result<Vec<u8>, ApiError>;
+
+pub(crate) fn handle(state: &mut State, request: &Request<Vec<u8>>) -> Response<Vec<u8>> {
+    let (status, body) = match route(state, request) {
+        Ok(body) => (200, body),
+        Err(ApiError(status, message)) => (
+            status,
+            to_json(&ErrorResponseSchema {
+                error: ErrorSchema {
+                    code: status,
+                    message,
+                },
+            }),
+        ),
+    };
+    Response::builder()
+        .status(status)
+        .header(CONTENT_TYPE, "application/json")
+        .body(body)
+        .expect("status and header are valid")
+}
+
+fn route(state: &mut State, request: &Request<Vec<u8>>) -> Reply {
+    let path = request.uri().path().trim_matches('/');
+    let segments: Vec<&str> = path.split('/').collect();
+    let query = Query::parse(request.uri().query().unwrap_or_default());
+
+    match (request.method(), segments.as_slice()) {
+        (&Method::GET, [""]) => status(state),
+        (&Method::GET, ["characters", name]) => {
+            let character = state
+                .characters
+                .iter()
+                .find(|character| character.name == *name)
+                .ok_or_else(|| ApiError::new(404, "Character not found."))?;
+            data(character)
+        }
+        (&Method::GET, ["items"]) => paginate(&items(state, &query)?, &query),
+        (&Method::GET, ["items", code]) => {
+            let item = find_item(state, code)?.clone();
+            let ge = state
+                .ge
+                .iter()
+                .find(|listing| listing.code == item.code)
+                .cloned()
+                .unwrap_or_else(|| GEItemSchema {
+                    code: item.code.clone(),
+                    stock: 0,
+                    sell_price: 0,
+                    buy_price: 0,
+                });
+            data(SingleItemSchema { ge, item })
+        }
+        (&Method::GET, ["ge"]) => paginate(&state.ge, &query),
+        (&Method::GET, ["ge", code]) => data(find_listing(state, code)?),
+        (&Method::GET, ["monsters"]) => paginate(&monsters(state, &query)?, &query),
+        (&Method::GET, ["monsters", code]) => data(
+            state
+                .monsters
+                .iter()
+                .find(|monster| monster.code == *code)
+                .ok_or_else(|| ApiError::new(404, "Monster not found."))?,
+        ),
+        (&Method::GET, ["resources"]) => paginate(&resources(state, &query)?, &query),
+        (&Method::GET, ["resources", code]) => data(
+            state
+                .resources
+                .iter()
+                .find(|resource| resource.code == *code)
+                .ok_or_else(|| ApiError::new(404, "Resource not found."))?,
+        ),
+        (&Method::GET, ["maps"]) => paginate(&maps(state, &query)?, &query),
+        (&Method::GET, ["maps", x, y]) => {
+            let (x, y) = (x.parse::<i32>(), y.parse::<i32>());
+            data(
+                state
+                    .maps
+                    .iter()
+                    .find(|map| Ok(map.x) == x && Ok(map.y) == y)
+                    .ok_or_else(|| ApiError::new(404, "Map not found."))?,
+            )
+        }
+        (&Method::POST, ["characters", "create"]) => {
+            authenticate(state, request)?;
+            create_character(state, body(request)?)
+        }
+        (&Method::GET, ["my", "characters"]) => {
+            authenticate(state, request)?;
+            data(&state.characters)
+        }
+        (&Method::GET, ["my", "bank", "gold"]) => {
+            authenticate(state, request)?;
+            data(GoldSchema {
+                quantity: state.bank_gold,
+            })
+        }
+        (&Method::GET, ["my", "logs"]) => {
+            authenticate(state, request)?;
+            paginate(&state.logs, &query)
+        }
+        (&Method::GET, ["my", "bank", "items"]) => {
+            authenticate(state, request)?;
+            let bank: Vec<_> = state
+                .bank
+                .iter()
+                .filter(|item| query.get("item_code").is_none_or(|code| item.code == code))
+                .cloned()
+                .collect();
+            paginate(&bank, &query)
+        }
+        (&Method::POST, ["my", name, "action", action @ ..]) => {
+            authenticate(state, request)?;
+            let now = state.now();
+            let index = state
+                .characters
+                .iter()
+                .position(|character| character.name == *name)
+                .ok_or_else(|| ApiError::new(498, "Character not found."))?;
+            let remaining = (state.characters[index].cooldown_expiration - now).num_seconds();
+            if remaining > 0 {
+                return Err(ApiError::new(
+                    499,
+                    format!("Character in cooldown: {remaining} seconds left."),
+                ));
+            }
+            let mut character = state.characters[index].clone();
+            let reply = action_route(state, &mut character, now, action, request)?;
+            state.characters[index] = character;
+            Ok(reply)
+        }
+        _ => Err(ApiError::new(404, "Not found.")),
+    }
+}
+
+fn action_route(
+    state: &mut State,
+    character: &mut CharacterSchema,
+    now: DateTime<Utc>,
+    action: &[&str],
+    request: &Request<Vec<u8>>,
+) -> Reply {
+    match action {
+        ["move"] => action_move(state, character, now, body(request)?),
+        ["fight"] => action_fight(state, character, now),
+        ["gathering"] => action_gathering(state, character, now),
+        ["equip"] => action_equip(state, character, now, body(request)?),
+        ["unequip"] => action_unequip(state, character, now, body(request)?),
+        ["bank", "deposit"] => action_deposit_bank(state, character, now, body(request)?),
+        ["bank", "withdraw"] => action_withdraw_bank(state, character, now, body(request)?),
+        ["bank", "deposit", "gold"] => {
+            action_deposit_bank_gold(state, character, now, body(request)?)
+        }
+        ["bank", "withdraw", "gold"] => {
+            action_withdraw_bank_gold(state, character, now, body(request)?)
+        }
+        ["crafting"] => action_crafting(state, character, now, body(request)?),
+        ["recycling"] => action_recycling(state, character, now, body(request)?),
+        ["delete"] => action_delete_item(state, character, now, body(request)?),
+        ["ge", "buy"] => action_ge_buy_item(state, character, now, body(request)?),
+        ["ge", "sell"] => action_ge_sell_item(state, character, now, body(request)?),
+        ["task", "new"] => action_accept_new_task(state, character, now),
+        ["task", "complete"] => action_complete_task(state, character, now),
+        ["task", "exchange"] => action_task_exchange(state, character, now),
+        _ => Err(ApiError::new(404, "Not found.")),
+    }
+}
+
+fn status(state: &State) -> Reply {
+    data(StatusSchema {
+        status: "online".to_string(),
+        version: API_VERSION.trim_start_matches('v').to_string(),
+        characters_online: state.characters.len() as u32,
+        announcements: Vec::new(),
+        last_wipe: String::new(),
+        next_wipe: String::new(),
+    })
+}
+
+#[derive(Deserialize)]
+struct CreateCharacter {
+    name: String,
+    skin: String,
+}
+
+fn create_character(state: &mut State, request: CreateCharacter) -> Reply {
+    if state
+        .characters
+        .iter()
+        .any(|character| character.name == request.name)
+    {
+        return Err(ApiError::new(494, "Name already used."));
+    }
+    if state.characters.len() >= MAX_CHARACTERS {
+        return Err(ApiError::new(
+            495,
+            "Maximum characters reached on your account.",
+        ));
+    }
+    let character = new_character(&request.name, &request.skin, state.now());
+    state.characters.push(character.clone());
+    data(character)
+}
+
+#[derive(Deserialize)]
+struct Destination {
+    x: i32,
+    y: i32,
+}
+
+fn action_move(
+    state: &mut State,
+    character: &mut CharacterSchema,
+    now: DateTime<Utc>,
+    destination: Destination,
+) -> Reply {
+    let map = state
+        .maps
+        .iter()
+        .find(|map| map.x == destination.x && map.y == destination.y)
+        .ok_or_else(|| ApiError::new(404, "Map not found."))?
+        .clone();
+    if (character.x, character.y) == (map.x, map.y) {
+        return Err(ApiError::new(490, "Character already at destination."));
+    }
+
+    let distance = Position::from(&*character).manhattan_distance(&Position::from(&map));
+    let seconds = distance.saturating_mul(MOVE_SECONDS_PER_TILE.into());
+    character.x = map.x;
+    character.y = map.y;
+    let cooldown = start_cooldown(
+        character,
+        now,
+        u32::try_from(seconds).unwrap_or(u32::MAX),
+        ReasonSchema::Movement,
+    );
+    let destination = DestinationResponseSchema {
+        name: map.name,
+        x: map.x,
+        y: map.y,
+    };
+    log(
+        state,
+        character,
+        format!("Move to ({}, {})", destination.x, destination.y),
+        LogContentSchema::Movement {
+            destination: destination.clone(),
+        },
+    );
+    data(CharacterMovementDataSchema {
+        cooldown,
+        destination,
+        character: character.clone(),
+    })
+}
+
+/// Fights are simulated without blocks, a win gives the minimum gold and no xp or drops, and
+/// counts for a monsters task on this monster
+fn action_fight(state: &mut State, character: &mut CharacterSchema, now: DateTime<Utc>) -> Reply {
+    let monster = content_code(state, character, &MapContentTypeSchema::Monster)
+        .and_then(|code| state.monsters.iter().find(|monster| monster.code == code))
+        .ok_or_else(|| ApiError::new(598, "Monster not found on this map."))?
+        .clone();
+
+    let simulated = combat::simulate(character, &monster);
+    let gold = match simulated.result {
+        ResultSchema::Win => monster.min_gold,
+        ResultSchema::Loss => 0,
+    };
+    character.gold = character.gold.saturating_add(gold);
+    if simulated.result == ResultSchema::Win
+        && character.task_type == Some(TaskTypeSchema::Monsters)
+        && character.task == monster.code
+    {
+        character.task_progress = character
+            .task_progress
+            .saturating_add(1)
+            .min(character.task_total);
+    }
+    let cooldown = start_cooldown(character, now, simulated.cooldown, ReasonSchema::Fight);
+    let fight = FightSchema {
+        xp: 0,
+        gold,
+        drops: Vec::new(),
+        turns: simulated.turns,
+        monster_blocked_hits: simulated.monster_blocked_hits,
+        player_blocked_hits: simulated.player_blocked_hits,
+        logs: Vec::new(),
+        result: simulated.result,
+    };
+    log(
+        state,
+        character,
+        format!("Fight against {}", monster.code),
+        LogContentSchema::Fight {
+            fight: fight.clone(),
+        },
+    );
+    data(CharacterFightDataSchema {
+        cooldown,
+        fight,
+        character: character.clone(),
+    })
+}
+
+/// Only the drops with a rate of 1 are given, with their minimum quantity
+fn action_gathering(
+    state: &mut State,
+    character: &mut CharacterSchema,
+    now: DateTime<Utc>,
+) -> Reply {
+    let resource = content_code(state, character, &MapContentTypeSchema::Resource)
+        .and_then(|code| {
+            state
+                .resources
+                .iter()
+                .find(|resource| resource.code == code)
+        })
+        .ok_or_else(|| ApiError::new(598, "Resource not found on this map."))?
+        .clone();
+    let skill = character
+        .skill(&resource.skill)
+        .ok_or_else(|| ApiError::new(598, "Resource not found on this map."))?;
+    if skill.level < resource.level {
+        return Err(ApiError::new(493, "Not skill level required."));
+    }
+
+    let drops: Vec<DropSchema> = resource
+        .drops
+        .iter()
+        .filter(|drop| drop.rate == 1)
+        .map(|drop| DropSchema {
+            code: drop.code.clone(),
+            quantity: drop.min_quantity,
+        })
+        .collect();
+    for drop in &drops {
+        add_to_inventory(character, &drop.code, drop.quantity)?;
+    }
+    let cooldown = start_cooldown(character, now, GATHERING_SECONDS, ReasonSchema::Gathering);
+    let details = SkillInfoSchema {
+        xp: 0,
+        items: drops,
+    };
+    let description = format!("Gather {}", resource.code);
+    log(
+        state,
+        character,
+        description,
+        LogContentSchema::Gathering {
+            details: details.clone(),
+        },
+    );
+    data(SkillDataSchema {
+        cooldown,
+        details,
+        character: character.clone(),
+    })
+}
+
+#[derive(Deserialize)]
+struct Equip {
+    code: String,
+    slot: SlotTypeSchema,
+}
+
+fn action_equip(
+    state: &mut State,
+    character: &mut CharacterSchema,
+    now: DateTime<Utc>,
+    request: Equip,
+) -> Reply {
+    let item = find_item(state, &request.code)?.clone();
+    if !fits(&item.r#type, &request.slot) {
+        return Err(ApiError::new(422, "Item cannot be equipped in this slot."));
+    }
+    if character.inventory_count(&item.code) == 0 {
+        return Err(ApiError::new(478, "Missing item or insufficient quantity."));
+    }
+    if item.r#type == ItemTypeSchema::Artifact && character.equipment().is_equipped(&item.code) {
+        return Err(ApiError::new(485, "This item is already equipped."));
+    }
+    if character.slot(&request.slot).is_some() {
+        return Err(ApiError::new(491, "Slot is not empty."));
+    }
+    if item.level > character.level {
+        return Err(ApiError::new(496, "Character level is insufficient."));
+    }
+
+    remove_from_inventory(character, &item.code, 1);
+    if let Some(slot) = character.slot_mut(&request.slot) {
+        *slot = item.code.clone();
+    }
+    apply_effects(character, &item, true);
+    let cooldown = start_cooldown(character, now, INVENTORY_SECONDS, ReasonSchema::Equip);
+    log(
+        state,
+        character,
+        format!("Equip {} in {}", item.code, request.slot),
+        LogContentSchema::Equip {
+            slot: request.slot.clone(),
+            item: item.clone(),
+        },
+    );
+    data(EquipRequestSchema {
+        cooldown,
+        slot: request.slot,
+        item,
+        character: character.clone(),
+    })
+}
+
+#[derive(Deserialize)]
+struct Unequip {
+    slot: SlotTypeSchema,
+}
+
+fn action_unequip(
+    state: &mut State,
+    character: &mut CharacterSchema,
+    now: DateTime<Utc>,
+    request: Unequip,
+) -> Reply {
+    let code = character
+        .slot(&request.slot)
+        .ok_or_else(|| ApiError::new(491, "Slot is empty."))?
+        .to_string();
+    let item = find_item(state, &code)?.clone();
+
+    if let Some(slot) = character.slot_mut(&request.slot) {
+        slot.clear();
+    }
+    apply_effects(character, &item, false);
+    add_to_inventory(character, &code, 1)?;
+    let cooldown = start_cooldown(character, now, INVENTORY_SECONDS, ReasonSchema::Unequip);
+    log(
+        state,
+        character,
+        format!("Unequip {} from {}", item.code, request.slot),
+        LogContentSchema::Unequip {
+            slot: request.slot.clone(),
+            item: item.clone(),
+        },
+    );
+    data(EquipRequestSchema {
+        cooldown,
+        slot: request.slot,
+        item,
+        character: character.clone(),
+    })
+}
+
+fn action_deposit_bank(
+    state: &mut State,
+    character: &mut CharacterSchema,
+    now: DateTime<Utc>,
+    request: SimpleItemSchema,
+) -> Reply {
+    if content_code(state, character, &MapContentTypeSchema::Bank).is_none() {
+        return Err(ApiError::new(598, "Bank not found on this map."));
+    }
+    let item = find_item(state, &request.code)?.clone();
+    if character.inventory_count(&item.code) < request.quantity {
+        return Err(ApiError::new(478, "Missing item or insufficient quantity."));
+    }
+
+    remove_from_inventory(character, &item.code, request.quantity);
+    match state.bank.iter_mut().find(|stack| stack.code == item.code) {
+        Some(stack) => stack.quantity = stack.quantity.saturating_add(request.quantity),
+        None => state.bank.push(request.clone()),
+    }
+    let cooldown = start_cooldown(character, now, INVENTORY_SECONDS, ReasonSchema::DepositBank);
+    log(
+        state,
+        character,
+        format!("Deposit {} {}", request.quantity, item.code),
+        LogContentSchema::DepositBank { item: item.clone() },
+    );
+    data(BankItemSchema {
+        cooldown,
+        item,
+        bank: state.bank.clone(),
+        character: character.clone(),
+    })
+}
+
+fn action_withdraw_bank(
+    state: &mut State,
+    character: &mut CharacterSchema,
+    now: DateTime<Utc>,
+    request: SimpleItemSchema,
+) -> Reply {
+    if content_code(state, character, &MapContentTypeSchema::Bank).is_none() {
+        return Err(ApiError::new(598, "Bank not found on this map."));
+    }
+    let item = find_item(state, &request.code)?.clone();
+    let Some(index) = state
+        .bank
+        .iter()
+        .position(|stack| stack.code == item.code && stack.quantity >= request.quantity)
+    else {
+        return Err(ApiError::new(478, "Missing item or insufficient quantity."));
+    };
+    add_to_inventory(character, &item.code, request.quantity)?;
+
+    state.bank[index].quantity -= request.quantity;
+    if state.bank[index].quantity == 0 {
+        state.bank.remove(index);
+    }
+    let cooldown = start_cooldown(
+        character,
+        now,
+        INVENTORY_SECONDS,
+        ReasonSchema::WithdrawBank,
+    );
+    log(
+        state,
+        character,
+        format!("Withdraw {} {}", request.quantity, item.code),
+        LogContentSchema::WithdrawBank { item: item.clone() },
+    );
+    data(BankItemSchema {
+        cooldown,
+        item,
+        bank: state.bank.clone(),
+        character: character.clone(),
+    })
+}
+
+/// Gold transactions have no content in `LogContentSchema` so they are not logged
+fn action_deposit_bank_gold(
+    state: &mut State,
+    character: &mut CharacterSchema,
+    now: DateTime<Utc>,
+    request: GoldSchema,
+) -> Reply {
+    if content_code(state, character, &MapContentTypeSchema::Bank).is_none() {
+        return Err(ApiError::new(598, "Bank not found on this map."));
+    }
+    if character.gold < request.quantity {
+        return Err(ApiError::new(492, "Insufficient gold on your character."));
+    }
+
+    character.gold -= request.quantity;
+    state.bank_gold = state.bank_gold.saturating_add(request.quantity);
+    data(GoldTransactionSchema {
+        cooldown: start_cooldown(character, now, INVENTORY_SECONDS, ReasonSchema::DepositBank),
+        bank: GoldSchema {
+            quantity: state.bank_gold,
+        },
+        character: character.clone(),
+    })
+}
+
+fn action_withdraw_bank_gold(
+    state: &mut State,
+    character: &mut CharacterSchema,
+    now: DateTime<Utc>,
+    request: GoldSchema,
+) -> Reply {
+    if content_code(state, character, &MapContentTypeSchema::Bank).is_none() {
+        return Err(ApiError::new(598, "Bank not found on this map."));
+    }
+    if state.bank_gold < request.quantity {
+        return Err(ApiError::new(460, "Insufficient gold in your bank."));
+    }
+
+    state.bank_gold -= request.quantity;
+    character.gold = character.gold.saturating_add(request.quantity);
+    data(GoldTransactionSchema {
+        cooldown: start_cooldown(
+            character,
+            now,
+            INVENTORY_SECONDS,
+            ReasonSchema::WithdrawBank,
+        ),
+        bank: GoldSchema {
+            quantity: state.bank_gold,
+        },
+        character: character.clone(),
+    })
+}
+
+/// Crafting is done in the workshop named after the craft skill
+fn action_crafting(
+    state: &mut State,
+    character: &mut CharacterSchema,
+    now: DateTime<Utc>,
+    request: SimpleItemSchema,
+) -> Reply {
+    let item = find_item(state, &request.code)?.clone();
+    let craft = item
+        .craft
+        .as_ref()
+        .ok_or_else(|| ApiError::new(404, "Craft not found."))?;
+    let skill = workshop_skill(state, character, &craft.skill)?;
+    if skill.level < craft.level {
+        return Err(ApiError::new(493, "Not skill level required."));
+    }
+
+    for ingredient in &craft.items {
+        let needed = ingredient.quantity.checked_mul(request.quantity);
+        if needed.is_none_or(|needed| character.inventory_count(&ingredient.code) < needed) {
+            return Err(ApiError::new(478, "Missing item or insufficient quantity."));
+        }
+    }
+    for ingredient in &craft.items {
+        remove_from_inventory(
+            character,
+            &ingredient.code,
+            ingredient.quantity * request.quantity,
+        );
+    }
+    let crafted = craft
+        .quantity
+        .checked_mul(request.quantity)
+        .ok_or_else(|| ApiError::new(497, "Character inventory is full."))?;
+    add_to_inventory(character, &item.code, crafted)?;
+
+    let cooldown = start_cooldown(
+        character,
+        now,
+        CRAFTING_SECONDS.saturating_mul(request.quantity),
+        ReasonSchema::Crafting,
+    );
+    let details = SkillInfoSchema {
+        xp: 0,
+        items: vec![DropSchema {
+            code: item.code.clone(),
+            quantity: crafted,
+        }],
+    };
+    log(
+        state,
+        character,
+        format!("Craft {crafted} {}", item.code),
+        LogContentSchema::Crafting {
+            details: details.clone(),
+        },
+    );
+    data(SkillDataSchema {
+        cooldown,
+        details,
+        character: character.clone(),
+    })
+}
+
+/// Each recycled item gives back one of each of its ingredients
+fn action_recycling(
+    state: &mut State,
+    character: &mut CharacterSchema,
+    now: DateTime<Utc>,
+    request: SimpleItemSchema,
+) -> Reply {
+    let item = find_item(state, &request.code)?.clone();
+    let craft = item
+        .craft
+        .as_ref()
+        .ok_or_else(|| ApiError::new(473, "This item cannot be recycled."))?;
+    let skill = workshop_skill(state, character, &craft.skill)?;
+    if skill.level < craft.level {
+        return Err(ApiError::new(493, "Not skill level required."));
+    }
+    if character.inventory_count(&item.code) < request.quantity {
+        return Err(ApiError::new(478, "Missing item or insufficient quantity."));
+    }
+
+    remove_from_inventory(character, &item.code, request.quantity);
+    let items: Vec<DropSchema> = craft
+        .items
+        .iter()
+        .map(|ingredient| DropSchema {
+            code: ingredient.code.clone(),
+            quantity: request.quantity,
+        })
+        .collect();
+    for drop in &items {
+        add_to_inventory(character, &drop.code, drop.quantity)?;
+    }
+
+    let cooldown = start_cooldown(
+        character,
+        now,
+        CRAFTING_SECONDS.saturating_mul(request.quantity),
+        ReasonSchema::Recycling,
+    );
+    let details = RecyclingItemsSchema { items };
+    log(
+        state,
+        character,
+        format!("Recycle {} {}", request.quantity, item.code),
+        LogContentSchema::Recycling {
+            details: details.clone(),
+        },
+    );
+    data(RecyclingDataSchema {
+        cooldown,
+        details,
+        character: character.clone(),
+    })
+}
+
+fn action_delete_item(
+    state: &mut State,
+    character: &mut CharacterSchema,
+    now: DateTime<Utc>,
+    request: SimpleItemSchema,
+) -> Reply {
+    if character.inventory_count(&request.code) < request.quantity {
+        return Err(ApiError::new(478, "Missing item or insufficient quantity."));
+    }
+
+    remove_from_inventory(character, &request.code, request.quantity);
+    let cooldown = start_cooldown(character, now, INVENTORY_SECONDS, ReasonSchema::DeleteItem);
+    log(
+        state,
+        character,
+        format!("Delete {} {}", request.quantity, request.code),
+        LogContentSchema::DeleteItem {
+            item: request.clone(),
+        },
+    );
+    data(DeleteItemSchema {
+        cooldown,
+        item: request,
+        character: character.clone(),
+    })
+}
+
+#[derive(Deserialize)]
+struct Trade {
+    code: String,
+    quantity: u32,
+    price: u32,
+}
+
+fn action_ge_buy_item(
+    state: &mut State,
+    character: &mut CharacterSchema,
+    now: DateTime<Utc>,
+    request: Trade,
+) -> Reply {
+    if content_code(state, character, &MapContentTypeSchema::GrandExchange).is_none() {
+        return Err(ApiError::new(598, "Grand Exchange not found on this map."));
+    }
+    let listing = find_listing(state, &request.code)?;
+    if listing.buy_price != request.price {
+        return Err(ApiError::new(482, "No item at this price."));
+    }
+    if listing.stock < request.quantity {
+        return Err(ApiError::new(480, "No stock for this item."));
+    }
+    let transaction = GETransactionSchema::buy(listing, request.quantity)
+        .filter(|transaction| transaction.total_price <= character.gold)
+        .ok_or_else(|| ApiError::new(492, "Insufficient gold on your character."))?;
+
+    add_to_inventory(character, &transaction.code, transaction.quantity)?;
+    character.gold -= transaction.total_price;
+    if let Some(listing) = state.ge.iter_mut().find(|item| item.code == request.code) {
+        listing.stock -= transaction.quantity;
+    }
+    trade(state, character, now, transaction, ReasonSchema::BuyGe)
+}
+
+fn action_ge_sell_item(
+    state: &mut State,
+    character: &mut CharacterSchema,
+    now: DateTime<Utc>,
+    request: Trade,
+) -> Reply {
+    if content_code(state, character, &MapContentTypeSchema::GrandExchange).is_none() {
+        return Err(ApiError::new(598, "Grand Exchange not found on this map."));
+    }
+    let listing = find_listing(state, &request.code)?;
+    if listing.sell_price != request.price {
+        return Err(ApiError::new(482, "No item at this price."));
+    }
+    if character.inventory_count(&request.code) < request.quantity {
+        return Err(ApiError::new(478, "Missing item or insufficient quantity."));
+    }
+    let transaction = GETransactionSchema::sell(listing, request.quantity)
+        .ok_or_else(|| ApiError::new(422, "Total price is too high."))?;
+
+    remove_from_inventory(character, &transaction.code, transaction.quantity);
+    character.gold = character.gold.saturating_add(transaction.total_price);
+    if let Some(listing) = state.ge.iter_mut().find(|item| item.code == request.code) {
+        listing.stock = listing.stock.saturating_add(transaction.quantity);
+    }
+    trade(state, character, now, transaction, ReasonSchema::SellGe)
+}
+
+fn trade(
+    state: &mut State,
+    character: &mut CharacterSchema,
+    now: DateTime<Utc>,
+    transaction: GETransactionSchema,
+    reason: ReasonSchema,
+) -> Reply {
+    let cooldown = start_cooldown(character, now, INVENTORY_SECONDS, reason.clone());
+    let (verb, content) = match reason {
+        ReasonSchema::BuyGe => (
+            "Buy",
+            LogContentSchema::BuyGe {
+                transaction: transaction.clone(),
+            },
+        ),
+        _ => (
+            "Sell",
+            LogContentSchema::SellGe {
+                transaction: transaction.clone(),
+            },
+        ),
+    };
+    log(
+        state,
+        character,
+        format!("{verb} {} {}", transaction.quantity, transaction.code),
+        content,
+    );
+    data(GETransactionListSchema {
+        cooldown,
+        transaction,
+        character: character.clone(),
+    })
+}
+
+/// The task is to kill the highest level monster the character can fight, ties are broken by
+/// code
+fn action_accept_new_task(
+    state: &mut State,
+    character: &mut CharacterSchema,
+    now: DateTime<Utc>,
+) -> Reply {
+    if content_code(state, character, &MapContentTypeSchema::TasksMaster).is_none() {
+        return Err(ApiError::new(598, "Tasks Master not found on this map."));
+    }
+    if !character.task.is_empty() {
+        return Err(ApiError::new(489, "Character already has a task."));
+    }
+    let monster = state
+        .monsters
+        .iter()
+        .filter(|monster| monster.level <= character.level)
+        .max_by(|a, b| a.level.cmp(&b.level).then_with(|| b.code.cmp(&a.code)))
+        .ok_or_else(|| ApiError::new(404, "No task available."))?;
+
+    let task = TaskSchema {
+        code: monster.code.clone(),
+        r#type: TaskTypeSchema::Monsters,
+        total: TASK_TOTAL,
+    };
+    character.task = task.code.clone();
+    character.task_type = Some(task.r#type.clone());
+    character.task_progress = 0;
+    character.task_total = task.total;
+    let cooldown = start_cooldown(character, now, INVENTORY_SECONDS, ReasonSchema::Task);
+    log(
+        state,
+        character,
+        format!("Accept a task on {}", task.code),
+        LogContentSchema::Task { task: task.clone() },
+    );
+    data(TaskDataSchema {
+        cooldown,
+        task,
+        character: character.clone(),
+    })
+}
+
+/// A completed task is rewarded with one tasks coin
+fn action_complete_task(
+    state: &mut State,
+    character: &mut CharacterSchema,
+    now: DateTime<Utc>,
+) -> Reply {
+    if content_code(state, character, &MapContentTypeSchema::TasksMaster).is_none() {
+        return Err(ApiError::new(598, "Tasks Master not found on this map."));
+    }
+    let Some(r#type) = character
+        .task_type
+        .clone()
+        .filter(|_| !character.task.is_empty())
+    else {
+        return Err(ApiError::new(487, "Character has no task."));
+    };
+    if character.task_progress < character.task_total {
+        return Err(ApiError::new(488, "Character has not completed the task."));
+    }
+
+    let reward = TaskRewardSchema {
+        code: TASKS_COIN.to_string(),
+        quantity: 1,
+    };
+    add_to_inventory(character, &reward.code, reward.quantity)?;
+    let task = TaskSchema {
+        code: std::mem::take(&mut character.task),
+        r#type,
+        total: character.task_total,
+    };
+    character.task_type = None;
+    character.task_progress = 0;
+    character.task_total = 0;
+    let cooldown = start_cooldown(character, now, INVENTORY_SECONDS, ReasonSchema::Task);
+    log(
+        state,
+        character,
+        format!("Complete the task on {}", task.code),
+        LogContentSchema::Task { task },
+    );
+    data(TaskRewardDataSchema {
+        cooldown,
+        reward,
+        character: character.clone(),
+    })
+}
+
+/// Tasks coins are exchanged for the first item by code, exchanges have no content in
+/// `LogContentSchema` so they are not logged
+fn action_task_exchange(
+    state: &mut State,
+    character: &mut CharacterSchema,
+    now: DateTime<Utc>,
+) -> Reply {
+    if content_code(state, character, &MapContentTypeSchema::TasksMaster).is_none() {
+        return Err(ApiError::new(598, "Tasks Master not found on this map."));
+    }
+    if character.inventory_count(TASKS_COIN) < TASK_EXCHANGE_COINS {
+        return Err(ApiError::new(478, "Missing item or insufficient quantity."));
+    }
+    let code = state
+        .items
+        .iter()
+        .map(|item| item.code.as_str())
+        .filter(|code| *code != TASKS_COIN)
+        .min()
+        .ok_or_else(|| ApiError::new(404, "No reward available."))?;
+
+    let reward = TaskRewardSchema {
+        code: code.to_string(),
+        quantity: 1,
+    };
+    remove_from_inventory(character, TASKS_COIN, TASK_EXCHANGE_COINS);
+    add_to_inventory(character, &reward.code, reward.quantity)?;
+    data(TaskRewardDataSchema {
+        cooldown: start_cooldown(character, now, INVENTORY_SECONDS, ReasonSchema::Task),
+        reward,
+        character: character.clone(),
+    })
+}
+
+fn authenticate(state: &State, request: &Request<Vec<u8>>) -> Result<(), ApiError> {
+    let expected = format!("Bearer {}", state.token);
+    match request.headers().get(AUTHORIZATION) {
+        Some(value) if value.as_bytes() == expected.as_bytes() => Ok(()),
+        _ => Err(ApiError::new(401, "Invalid token.")),
+    }
+}
+
+fn body<T: DeserializeOwned>(request: &Request<Vec<u8>>) -> Result<T, ApiError> {
+    serde_json::from_slice(request.body()).map_err(|e| ApiError::new(422, e.to_string()))
+}
+
+fn to_json<T: Serialize>(value: &T) -> Vec<u8> {
+    serde_json::to_vec(value).expect("schemas serialize to JSON")
+}
+
+fn data<T: Serialize>(data: T) -> Reply {
+    Ok(to_json(&ResponseSchema { data }))
+}
+
+struct Query {
+    page: u32,
+    size: u32,
+    /// Every other parameter, percent-decoded
+    filters: Vec<(String, String)>,
+}
+
+impl Query {
+    fn parse(query: &str) -> Self {
+        let mut parsed = Self {
+            page: 1,
+            size: 50,
+            filters: Vec::new(),
+        };
+        for (key, value) in query.split('&').filter_map(|pair| pair.split_once('=')) {
+            let (key, value) = (percent_decode(key), percent_decode(value));
+            match (key.as_str(), value.parse()) {
+                ("page", Ok(page)) => parsed.page = page,
+                ("size", Ok(size)) => parsed.size = size,
+                _ => parsed.filters.push((key, value)),
+            }
+        }
+        parsed
+    }
+
+    fn get(&self, key: &str) -> Option<&str> {
+        self.filters
+            .iter()
+            .find(|(filter, _)| filter == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// The API rejects filters that don't parse with a validation error
+    fn parsed<T: FromStr>(&self, key: &str) -> Result<Option<T>, ApiError> {
+        self.get(key)
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|_| ApiError::new(422, format!("Invalid {key}: {value}.")))
+            })
+            .transpose()
+    }
+
+    fn level_range(&self) -> Result<RangeInclusive<u32>, ApiError> {
+        Ok(self.parsed("min_level")?.unwrap_or(0)..=self.parsed("max_level")?.unwrap_or(u32::MAX))
+    }
+}
+
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        let hex = bytes
+            .get(index + 1..index + 3)
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (bytes[index], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                index += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                index += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+fn paginate<T: Clone + Serialize>(all: &[T], query: &Query) -> Reply {
+    if query.page == 0 || !(1..=100).contains(&query.size) {
+        return Err(ApiError::new(422, "Invalid page or size."));
+    }
+    // Pages past the end are empty rather than an error, even when the offset overflows
+    let start = (query.page - 1)
+        .checked_mul(query.size)
+        .map_or(usize::MAX, |start| start as usize);
+    Ok(to_json(&PaginatedResponseSchema {
+        data: all
+            .iter()
+            .skip(start)
+            .take(query.size as usize)
+            .cloned()
+            .collect(),
+        total: all.len() as u32,
+        page: query.page,
+        size: query.size,
+        pages: (all.len() as u32).div_ceil(query.size),
+    }))
+}
+
+fn items(state: &State, query: &Query) -> Result<Vec<ItemSchema>, ApiError> {
+    let levels = query.level_range()?;
+    let r#type = query.parsed::<ItemTypeSchema>("type")?;
+    let craft_skill = query.parsed::<CraftSkillSchema>("craft_skill")?;
+    let name = query.get("name").map(str::to_lowercase);
+    let craft_material = query.get("craft_material");
+    Ok(state
+        .items
+        .iter()
+        .filter(|item| levels.contains(&item.level))
+        .filter(|item| r#type.as_ref().is_none_or(|r#type| item.r#type == *r#type))
+        .filter(|item| {
+            name.as_ref()
+                .is_none_or(|name| item.name.to_lowercase().contains(name))
+        })
+        .filter(|item| {
+            craft_skill.as_ref().is_none_or(|skill| {
+                item.craft
+                    .as_ref()
+                    .is_some_and(|craft| craft.skill == *skill)
+            })
+        })
+        .filter(|item| {
+            craft_material.is_none_or(|material| {
+                item.craft.as_ref().is_some_and(|craft| {
+                    craft
+                        .items
+                        .iter()
+                        .any(|ingredient| ingredient.code == material)
+                })
+            })
+        })
+        .cloned()
+        .collect())
+}
+
+fn drops(drops: &[DropRateSchema], query: &Query) -> bool {
+    query
+        .get("drop")
+        .is_none_or(|code| drops.iter().any(|drop| drop.code == code))
+}
+
+fn monsters(state: &State, query: &Query) -> Result<Vec<MonsterSchema>, ApiError> {
+    let levels = query.level_range()?;
+    Ok(state
+        .monsters
+        .iter()
+        .filter(|monster| levels.contains(&monster.level) && drops(&monster.drops, query))
+        .cloned()
+        .collect())
+}
+
+fn resources(state: &State, query: &Query) -> Result<Vec<ResourceSchema>, ApiError> {
+    let levels = query.level_range()?;
+    let skill = query.parsed::<SkillSchema>("skill")?;
+    Ok(state
+        .resources
+        .iter()
+        .filter(|resource| levels.contains(&resource.level) && drops(&resource.drops, query))
+        .filter(|resource| skill.as_ref().is_none_or(|skill| resource.skill == *skill))
+        .cloned()
+        .collect())
+}
+
+fn maps(state: &State, query: &Query) -> Result<Vec<MapSchema>, ApiError> {
+    let content_type = query.parsed::<MapContentTypeSchema>("content_type")?;
+    let content_code = query.get("content_code");
+    Ok(state
+        .maps
+        .iter()
+        .filter(|map| {
+            content_type.as_ref().is_none_or(|r#type| {
+                map.content
+                    .as_ref()
+                    .is_some_and(|content| content.r#type == *r#type)
+            })
+        })
+        .filter(|map| {
+            content_code.is_none_or(|code| {
+                map.content
+                    .as_ref()
+                    .is_some_and(|content| content.code == code)
+            })
+        })
+        .cloned()
+        .collect())
+}
+
+fn find_listing<'a>(state: &'a State, code: &str) -> Result<&'a GEItemSchema, ApiError> {
+    state
+        .ge
+        .iter()
+        .find(|item| item.code == code)
+        .ok_or_else(|| ApiError::new(404, "Item not found."))
+}
+
+fn find_item<'a>(state: &'a State, code: &str) -> Result<&'a ItemSchema, ApiError> {
+    state
+        .items
+        .iter()
+        .find(|item| item.code == code)
+        .ok_or_else(|| ApiError::new(404, "Item not found."))
+}
+
+/// Code of the content of the tile the character stands on, if it has the expected type
+fn content_code<'a>(
+    state: &'a State,
+    character: &CharacterSchema,
+    r#type: &MapContentTypeSchema,
+) -> Option<&'a str> {
+    state
+        .maps
+        .iter()
+        .find(|map| map.x == character.x && map.y == character.y)
+        .and_then(|map| map.content.as_ref())
+        .filter(|content| content.r#type == *r#type)
+        .map(|content| content.code.as_str())
+}
+
+/// Progress of `skill` if the character stands in its workshop
+fn workshop_skill(
+    state: &State,
+    character: &CharacterSchema,
+    skill: &CraftSkillSchema,
+) -> Result<SkillProgress, ApiError> {
+    content_code(state, character, &MapContentTypeSchema::Workshop)
+        .filter(|code| *code == skill.to_string())
+        .and_then(|_| character.skill(skill))
+        .ok_or_else(|| ApiError::new(598, "Workshop not found on this map."))
+}
+
+/// Called once the cooldown of the action started, the log carries it
+fn log(
+    state: &mut State,
+    character: &CharacterSchema,
+    description: String,
+    content: LogContentSchema,
+) {
+    state.logs.push(LogSchema {
+        character: character.name.clone(),
+        account: ACCOUNT.to_string(),
+        content,
+        description,
+        cooldown: character.cooldown,
+        cooldown_expiration: Some(character.cooldown_expiration),
+        created_at: state.now(),
+    });
+}
+
+fn start_cooldown(
+    character: &mut CharacterSchema,
+    now: DateTime<Utc>,
+    seconds: u32,
+    reason: ReasonSchema,
+) -> CooldownSchema {
+    let expiration = now + TimeDelta::seconds(seconds.into());
+    character.cooldown = seconds;
+    character.cooldown_expiration = expiration;
+    CooldownSchema {
+        total_seconds: seconds,
+        remaining_seconds: seconds,
+        started_at: now,
+        expiration,
+        reason,
+    }
+}
+
+fn apply_effects(character: &mut CharacterSchema, item: &ItemSchema, equip: bool) {
+    for effect in &item.effects {
+        if let Some(stat) = character.stat_mut(&effect.name) {
+            *stat = if equip {
+                stat.saturating_add(effect.value)
+            } else {
+                stat.saturating_sub(effect.value)
+            };
+        }
+    }
+}
+
+/// Stacks on the slot already holding `code`, or takes the first empty slot, the inventory is full
+/// when there is no such slot or `inventory_max_items` would be exceeded
+fn add_to_inventory(
+    character: &mut CharacterSchema,
+    code: &str,
+    quantity: u32,
+) -> Result<(), ApiError> {
+    let full = || ApiError::new(497, "Character inventory is full.");
+    if character.inventory_free_space() < quantity {
+        return Err(full());
+    }
+    let index = character
+        .inventory
+        .iter()
+        .position(|slot| slot.code == code)
+        .or_else(|| {
+            character
+                .inventory
+                .iter()
+                .position(|slot| slot.code.is_empty())
+        })
+        .ok_or_else(full)?;
+    let slot = &mut character.inventory[index];
+    slot.quantity = slot.quantity.checked_add(quantity).ok_or_else(full)?;
+    slot.code = code.to_string();
+    Ok(())
+}
+
+fn remove_from_inventory(character: &mut CharacterSchema, code: &str, mut quantity: u32) {
+    for slot in character
+        .inventory
+        .iter_mut()
+        .filter(|slot| slot.code == code)
+    {
+        let taken = slot.quantity.min(quantity);
+        slot.quantity -= taken;
+        quantity -= taken;
+        if slot.quantity == 0 {
+            slot.code.clear();
+        }
+    }
+}